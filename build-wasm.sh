@@ -23,6 +23,12 @@ PROFILE=${1:-release}
 FAST=${FAST:-0}
 COMMON_FLAGS=(--target web --out-dir page/pkg --no-typescript)
 
+# Optimized builds sign win codes and won't compile without a real key.
+if [ "$PROFILE" != "dev" ] && [ -z "$GUNDAY_ATTESTATION_KEY" ]; then
+    echo "❌ GUNDAY_ATTESTATION_KEY is not set. Export it, or build with the dev profile."
+    exit 1
+fi
+
 case "$PROFILE" in
     dev)
         echo "🔨 Building WASM package (dev profile, fastest builds, skipping wasm-opt)..."
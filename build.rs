@@ -1,25 +1,72 @@
 use std::error::Error;
+use std::fmt::Write as _;
 use std::fs::File;
 use std::path::{Path, PathBuf};
 
 const MAX_TEXTURE_DIMENSION: usize = 8192;
 const LEVEL_DIR: &str = "page/assets/levels";
+// Loaded whole by the parallax layer instead of as a level layer.
+const PARALLAX_IMAGE: &str = "background";
 
 fn main() {
+    attestation_key_cfg();
     if let Err(err) = run() {
         panic!("build script failed: {err}");
     }
 }
 
 fn run() -> Result<(), Box<dyn Error>> {
-    let level_count = split_image("level")?;
-    let foreground_count = split_image("foreground")?;
+    let mut table = String::from("pub const LEVEL_IMAGE_CHUNKS: &[(&str, usize)] = &[\n");
+    for basename in level_images()? {
+        let count = split_image(&basename)?;
+        writeln!(table, "    (\"{basename}\", {count}),")?;
+    }
+    table.push_str("];\n");
 
-    println!("cargo:rustc-env=LEVEL_CHUNK_COUNT={level_count}");
-    println!("cargo:rustc-env=FOREGROUND_CHUNK_COUNT={foreground_count}");
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
+    std::fs::write(out_dir.join("level_image_chunks.rs"), table)?;
     Ok(())
 }
 
+/// Sets `gunday_attestation_key` when a signing key was given, so release
+/// builds without one can refuse to compile.
+fn attestation_key_cfg() {
+    println!("cargo:rustc-check-cfg=cfg(gunday_attestation_key)");
+    println!("cargo:rerun-if-env-changed=GUNDAY_ATTESTATION_KEY");
+    // An unset CI secret still arrives as an empty variable.
+    if std::env::var("GUNDAY_ATTESTATION_KEY").is_ok_and(|key| !key.is_empty()) {
+        println!("cargo:rustc-cfg=gunday_attestation_key");
+    }
+}
+
+/// Every source image in the levels directory, i.e. each PNG that isn't one
+/// of the `_partN` chunks this script writes.
+fn level_images() -> Result<Vec<String>, Box<dyn Error>> {
+    println!("cargo:rerun-if-changed={LEVEL_DIR}");
+
+    let mut basenames = Vec::new();
+    for entry in std::fs::read_dir(LEVEL_DIR)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|extension| extension != "png") {
+            continue;
+        }
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        if stem != PARALLAX_IMAGE && !is_chunk(stem) {
+            basenames.push(stem.to_string());
+        }
+    }
+    basenames.sort();
+    Ok(basenames)
+}
+
+fn is_chunk(stem: &str) -> bool {
+    stem.rsplit_once("_part").is_some_and(|(_, index)| {
+        !index.is_empty() && index.bytes().all(|byte| byte.is_ascii_digit())
+    })
+}
+
 fn split_image(basename: &str) -> Result<usize, Box<dyn Error>> {
     let source_path = Path::new(LEVEL_DIR).join(format!("{basename}.png"));
     println!("cargo:rerun-if-changed={}", source_path.display());
@@ -90,8 +137,8 @@ fn write_png(
     trns: Option<&[u8]>,
     data: &[u8],
 ) -> Result<(), Box<dyn Error>> {
-    let file = File::create(path)?;
-    let mut encoder = png::Encoder::new(file, width, height);
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, width, height);
     encoder.set_color(color_type);
     encoder.set_depth(bit_depth);
     if let Some(palette) = palette {
@@ -102,5 +149,12 @@ fn write_png(
     }
     let mut writer = encoder.write_header()?;
     writer.write_image_data(data)?;
+    writer.finish()?;
+
+    // Rewriting unchanged chunks would touch the watched directory and rerun
+    // this script on every build.
+    if std::fs::read(path).ok().as_deref() != Some(bytes.as_slice()) {
+        std::fs::write(path, bytes)?;
+    }
     Ok(())
 }
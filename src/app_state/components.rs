@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+
+/// Top level flow of the app. Gameplay only advances in `Playing`; every
+/// other state keeps `Time<Virtual>` paused. Each screen lives from its
+/// state's `OnEnter` to its `OnExit`.
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AppState {
+    #[default]
+    Boot,
+    Loading,
+    Title,
+    Playing,
+    Results,
+}
+
+/// Sent when a run is won or lost, after the result went out to the page.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct RunFinished {
+    pub win: bool,
+}
+
+/// Outcome of the last finished run, shown on the results screen.
+#[derive(Resource, Default, Clone, Copy, Debug)]
+pub struct LastRun {
+    pub win: bool,
+    pub score: u32,
+}
+
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameMode {
+    #[default]
+    Normal,
+    Arcade,
+    Hardcore,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 3] = [GameMode::Normal, GameMode::Arcade, GameMode::Hardcore];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GameMode::Normal => "Normal",
+            GameMode::Arcade => "Arcade",
+            GameMode::Hardcore => "Hardcore",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            GameMode::Normal => "Continue from checkpoints",
+            GameMode::Arcade => "No continues",
+            GameMode::Hardcore => "One life, no continues",
+        }
+    }
+
+    /// `None` keeps the player's usual lives.
+    pub fn lives(self) -> Option<u8> {
+        match self {
+            GameMode::Hardcore => Some(1),
+            GameMode::Normal | GameMode::Arcade => None,
+        }
+    }
+
+    pub fn continues(self) -> bool {
+        self == GameMode::Normal
+    }
+
+    fn cycled(self, step: isize) -> GameMode {
+        let count = Self::ALL.len() as isize;
+        let index = Self::ALL.iter().position(|mode| *mode == self).unwrap_or(0) as isize;
+        Self::ALL[(index + step).rem_euclid(count) as usize]
+    }
+
+    pub fn next(self) -> GameMode {
+        self.cycled(1)
+    }
+
+    pub fn previous(self) -> GameMode {
+        self.cycled(-1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TitleMenuItem {
+    Start,
+    Mode,
+    Controls,
+    Audio,
+}
+
+impl TitleMenuItem {
+    pub const ALL: [TitleMenuItem; 4] = [
+        TitleMenuItem::Start,
+        TitleMenuItem::Mode,
+        TitleMenuItem::Controls,
+        TitleMenuItem::Audio,
+    ];
+}
+
+/// Title screen selection. The mode picked here is copied into `GameMode`
+/// when the run starts.
+#[derive(Resource, Default)]
+pub struct TitleMenu {
+    pub selected: usize,
+    pub mode: GameMode,
+}
+
+/// Share of the startup assets that has finished loading, never goes back.
+#[derive(Resource, Default)]
+pub struct LoadingProgress {
+    pub fraction: f32,
+}
+
+#[derive(Component)]
+pub struct LoadingScreenRoot;
+
+#[derive(Component)]
+pub struct LoadingBarFill;
+
+#[derive(Component)]
+pub struct LoadingPercentText;
+
+#[derive(Component)]
+pub struct TitleScreenRoot;
+
+#[derive(Component)]
+pub struct TitleMenuRow(pub usize);
+
+#[derive(Component)]
+pub struct TitleModeDescription;
+
+#[derive(Component)]
+pub struct ResultsScreenRoot;
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TitleConfig {
+    pub background: Color,
+    pub title_size: Vec2,
+    pub logo_size: f32,
+    pub item_font_size: f32,
+    pub description_font_size: f32,
+    pub hint_font_size: f32,
+    pub results_font_size: f32,
+    pub results_score_font_size: f32,
+}
+
+pub const TITLE_CONFIG: TitleConfig = TitleConfig {
+    // Matches the backdrop of the logo art.
+    background: Color::srgb(0.11, 0.11, 0.11),
+    // Keeps the aspect of `ui/title.png`.
+    title_size: Vec2::new(640.0, 118.0),
+    logo_size: 240.0,
+    item_font_size: 22.0,
+    description_font_size: 14.0,
+    hint_font_size: 14.0,
+    results_font_size: 40.0,
+    results_score_font_size: 24.0,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct LoadingConfig {
+    pub bar_size: Vec2,
+    pub bar_border: f32,
+    pub bar_color: Color,
+    pub font_size: f32,
+}
+
+pub const LOADING_CONFIG: LoadingConfig = LoadingConfig {
+    bar_size: Vec2::new(360.0, 18.0),
+    bar_border: 2.0,
+    bar_color: Color::srgb(1.0, 0.85, 0.2),
+    font_size: 18.0,
+};
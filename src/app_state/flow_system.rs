@@ -0,0 +1,98 @@
+use super::components::{AppState, GameMode, LastRun, RunFinished, TitleMenu};
+use crate::checkpoint::Checkpoints;
+use crate::player::PLAYER_CONFIG;
+use crate::player::components::{Player, PlayerLives};
+use crate::restart::GameRestartRequested;
+use crate::score::Score;
+use bevy::prelude::*;
+
+/// Nothing simulates until a run starts.
+pub fn freeze_gameplay_on_boot(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
+pub fn boot_system(mut next: ResMut<NextState<AppState>>) {
+    next.set(AppState::Loading);
+}
+
+/// Replays can't press start, so they go straight from the title into a
+/// run with the default mode.
+pub fn skip_title_for_playback_system(mut next: ResMut<NextState<AppState>>) {
+    next.set(AppState::Playing);
+}
+
+pub fn finish_run_system(
+    mut commands: Commands,
+    mut finished: EventReader<RunFinished>,
+    score: Res<Score>,
+    mut next: ResMut<NextState<AppState>>,
+) {
+    let Some(run) = finished.read().last() else {
+        return;
+    };
+    commands.insert_resource(LastRun {
+        win: run.win,
+        score: score.points,
+    });
+    next.set(AppState::Results);
+}
+
+pub fn resume_game_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
+
+pub fn pause_game_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
+/// Takes a screen down when its state is left.
+pub fn despawn_screen<T: Component>(mut commands: Commands, roots: Query<Entity, With<T>>) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+}
+
+/// Copies the picked mode over and, if a run was already played, restarts
+/// the game for a fresh one.
+pub fn start_run_system(
+    menu: Res<TitleMenu>,
+    last_run: Option<Res<LastRun>>,
+    mut mode: ResMut<GameMode>,
+    mut checkpoints: ResMut<Checkpoints>,
+    mut restart: EventWriter<GameRestartRequested>,
+) {
+    *mode = menu.mode;
+    checkpoints.disabled = !mode.continues();
+    if last_run.is_some() {
+        restart.write(GameRestartRequested);
+    }
+}
+
+/// A restart from the page while the results are up goes straight back into
+/// a run with the same mode.
+pub fn restart_from_results_system(
+    state: Res<State<AppState>>,
+    mut restart: EventReader<GameRestartRequested>,
+    mut next: ResMut<NextState<AppState>>,
+) {
+    if restart.read().last().is_some() && *state == AppState::Results {
+        next.set(AppState::Playing);
+    }
+}
+
+/// Gives the player the mode's lives, again whenever a restart spawns a new
+/// one.
+pub fn apply_game_mode_lives_system(
+    mode: Res<GameMode>,
+    mut players: Query<(Ref<Player>, &mut PlayerLives)>,
+) {
+    for (player, mut lives) in players.iter_mut() {
+        if !mode.is_changed() && !player.is_added() {
+            continue;
+        }
+        *lives = match mode.lives() {
+            Some(count) => PlayerLives::new(count, count),
+            None => PlayerLives::new(PLAYER_CONFIG.starting_lives, PLAYER_CONFIG.max_lives),
+        };
+    }
+}
@@ -0,0 +1,125 @@
+use super::components::{
+    AppState, LoadingBarFill, LoadingPercentText, LoadingProgress, LoadingScreenRoot,
+};
+use super::config::{LOADING_CONFIG, TITLE_CONFIG};
+use crate::assets::{GameAssets, LevelImages};
+use crate::level::CurrentLevel;
+use crate::soundtrack::{SoundtrackController, SoundtrackHandles};
+use bevy::asset::UntypedAssetId;
+use bevy::prelude::*;
+
+pub fn spawn_loading_screen(mut commands: Commands) {
+    commands
+        .spawn((
+            LoadingScreenRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(TITLE_CONFIG.background),
+            GlobalZIndex(10),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("LOADING"),
+                TextFont::from_font_size(LOADING_CONFIG.font_size),
+            ));
+            root.spawn((
+                Node {
+                    width: Val::Px(LOADING_CONFIG.bar_size.x),
+                    height: Val::Px(LOADING_CONFIG.bar_size.y),
+                    border: UiRect::all(Val::Px(LOADING_CONFIG.bar_border)),
+                    ..default()
+                },
+                BorderColor(LOADING_CONFIG.bar_color),
+            ))
+            .with_children(|bar| {
+                bar.spawn((
+                    LoadingBarFill,
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(LOADING_CONFIG.bar_color),
+                ));
+            });
+            root.spawn((
+                LoadingPercentText,
+                Text::new("0%"),
+                TextFont::from_font_size(LOADING_CONFIG.font_size),
+            ));
+        });
+}
+
+/// Counts every startup asset plus the level layers and the soundtrack sync,
+/// and only lets the title in once all of them are done. Failed loads count
+/// as done so a missing file can't hang the game here.
+pub fn loading_progress_system(
+    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
+    soundtrack: Res<SoundtrackHandles>,
+    controller: Res<SoundtrackController>,
+    level: (Res<CurrentLevel>, Res<LevelImages>),
+    mut progress: ResMut<LoadingProgress>,
+    mut next: ResMut<NextState<AppState>>,
+) {
+    let (current, level_images) = level;
+
+    let mut ids: Vec<UntypedAssetId> = game_assets.untyped_ids();
+    ids.push(soundtrack.manifest.id().untyped());
+    // The stems are only known once the manifest is in.
+    ids.extend(soundtrack.tracks.iter().map(|handle| handle.id().untyped()));
+    ids.push(current.handle.id().untyped());
+    // The layers are only known once the level file is in.
+    if current.applied {
+        ids.extend(level_images.untyped_ids());
+    }
+
+    let settled = |id: &UntypedAssetId| {
+        asset_server.is_loaded_with_dependencies(*id) || asset_server.load_state(*id).is_failed()
+    };
+    // Without a manifest or with a stem missing there is no soundtrack to
+    // wait for.
+    let music_settled = controller.is_ready()
+        || asset_server.load_state(&soundtrack.manifest).is_failed()
+        || soundtrack
+            .tracks
+            .iter()
+            .any(|handle| asset_server.load_state(handle).is_failed());
+    let loaded = ids.iter().filter(|id| settled(id)).count() + usize::from(music_settled);
+    let total = ids.len() + 1;
+
+    let fraction = loaded as f32 / total as f32;
+    if fraction > progress.fraction {
+        progress.fraction = fraction;
+    }
+
+    if loaded == total && current.applied {
+        progress.fraction = 1.0;
+        next.set(AppState::Title);
+    }
+}
+
+pub fn loading_bar_system(
+    progress: Res<LoadingProgress>,
+    mut fill: Query<&mut Node, With<LoadingBarFill>>,
+    mut text: Query<&mut Text, With<LoadingPercentText>>,
+) {
+    if !progress.is_changed() {
+        return;
+    }
+
+    let percent = progress.fraction * 100.0;
+    for mut node in fill.iter_mut() {
+        node.width = Val::Percent(percent);
+    }
+    for mut text in text.iter_mut() {
+        text.0 = format!("{}%", percent.floor() as u32);
+    }
+}
@@ -0,0 +1,10 @@
+pub mod components;
+pub mod config;
+pub mod flow_system;
+pub mod loading_system;
+pub mod plugin;
+pub mod results_system;
+pub mod title_system;
+
+pub use components::{AppState, RunFinished};
+pub use plugin::AppStatePlugin;
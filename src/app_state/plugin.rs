@@ -0,0 +1,66 @@
+use super::components::{
+    AppState, GameMode, LoadingProgress, LoadingScreenRoot, ResultsScreenRoot, RunFinished,
+    TitleMenu, TitleScreenRoot,
+};
+use super::flow_system::{
+    apply_game_mode_lives_system, boot_system, despawn_screen, finish_run_system,
+    freeze_gameplay_on_boot, pause_game_time, restart_from_results_system, resume_game_time,
+    skip_title_for_playback_system, start_run_system,
+};
+use super::loading_system::{loading_bar_system, loading_progress_system, spawn_loading_screen};
+use super::results_system::{results_navigation_system, spawn_results_screen};
+use super::title_system::{spawn_title_screen, title_menu_text_system, title_navigation_system};
+use crate::replay::InputPlayback;
+use bevy::prelude::*;
+
+pub struct AppStatePlugin;
+
+impl Plugin for AppStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<AppState>()
+            .init_resource::<GameMode>()
+            .init_resource::<TitleMenu>()
+            .init_resource::<LoadingProgress>()
+            .add_event::<RunFinished>()
+            .add_systems(Startup, freeze_gameplay_on_boot)
+            .add_systems(OnEnter(AppState::Loading), spawn_loading_screen)
+            .add_systems(
+                OnExit(AppState::Loading),
+                despawn_screen::<LoadingScreenRoot>,
+            )
+            .add_systems(OnEnter(AppState::Title), spawn_title_screen)
+            .add_systems(OnExit(AppState::Title), despawn_screen::<TitleScreenRoot>)
+            .add_systems(OnEnter(AppState::Playing), resume_game_time)
+            .add_systems(OnExit(AppState::Playing), pause_game_time)
+            .add_systems(
+                OnTransition {
+                    exited: AppState::Title,
+                    entered: AppState::Playing,
+                },
+                start_run_system,
+            )
+            .add_systems(OnEnter(AppState::Results), spawn_results_screen)
+            .add_systems(
+                OnExit(AppState::Results),
+                despawn_screen::<ResultsScreenRoot>,
+            )
+            .add_systems(
+                Update,
+                (
+                    boot_system.run_if(in_state(AppState::Boot)),
+                    loading_progress_system.run_if(in_state(AppState::Loading)),
+                    skip_title_for_playback_system
+                        .run_if(in_state(AppState::Title))
+                        .run_if(resource_exists::<InputPlayback>),
+                    title_navigation_system.run_if(in_state(AppState::Title)),
+                    results_navigation_system.run_if(in_state(AppState::Results)),
+                    finish_run_system,
+                    restart_from_results_system,
+                    apply_game_mode_lives_system,
+                    loading_bar_system.run_if(in_state(AppState::Loading)),
+                    title_menu_text_system.run_if(in_state(AppState::Title)),
+                )
+                    .chain(),
+            );
+    }
+}
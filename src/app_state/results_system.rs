@@ -0,0 +1,65 @@
+use super::components::{AppState, LastRun, ResultsScreenRoot};
+use super::config::TITLE_CONFIG;
+use crate::components::QuitRequested;
+use bevy::prelude::*;
+
+const HINT_COLOR: Color = Color::srgb(0.7, 0.7, 0.7);
+
+pub fn spawn_results_screen(mut commands: Commands, last_run: Option<Res<LastRun>>) {
+    let Some(last_run) = last_run else {
+        return;
+    };
+
+    let headline = if last_run.win { "YOU WIN" } else { "GAME OVER" };
+    commands
+        .spawn((
+            ResultsScreenRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            GlobalZIndex(10),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new(headline),
+                TextFont::from_font_size(TITLE_CONFIG.results_font_size),
+            ));
+            root.spawn((
+                Text::new(format!("Score: {}", last_run.score)),
+                TextFont::from_font_size(TITLE_CONFIG.results_score_font_size),
+            ));
+            root.spawn((
+                Text::new("Enter/A: title   Esc/B: quit"),
+                TextFont::from_font_size(TITLE_CONFIG.hint_font_size),
+                TextColor(HINT_COLOR),
+                Node {
+                    margin: UiRect::top(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+pub fn results_navigation_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut next: ResMut<NextState<AppState>>,
+) {
+    let pressed = |key: KeyCode, button: GamepadButton| {
+        keyboard.just_pressed(key) || gamepads.iter().any(|pad| pad.just_pressed(button))
+    };
+
+    if pressed(KeyCode::Enter, GamepadButton::South) {
+        next.set(AppState::Title);
+    } else if pressed(KeyCode::Escape, GamepadButton::East) {
+        commands.send_event(QuitRequested);
+    }
+}
@@ -0,0 +1,151 @@
+use super::components::{
+    AppState, TitleMenu, TitleMenuItem, TitleMenuRow, TitleModeDescription, TitleScreenRoot,
+};
+use super::config::TITLE_CONFIG;
+use crate::assets::GameAssets;
+use crate::audio::AudioSettingsScreen;
+use crate::player::rebind_screen::RebindScreen;
+use bevy::prelude::*;
+
+const ITEM_COLOR: Color = Color::srgb(0.7, 0.7, 0.7);
+const SELECTED_ITEM_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+pub fn spawn_title_screen(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    mut menu: ResMut<TitleMenu>,
+) {
+    // Keep the mode from the last run, start from the top row.
+    menu.selected = 0;
+    commands
+        .spawn((
+            TitleScreenRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(TITLE_CONFIG.background),
+            GlobalZIndex(10),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                ImageNode::new(game_assets.ui_title.clone()),
+                Node {
+                    width: Val::Px(TITLE_CONFIG.title_size.x),
+                    height: Val::Px(TITLE_CONFIG.title_size.y),
+                    ..default()
+                },
+            ));
+            root.spawn((
+                ImageNode::new(game_assets.ui_logo.clone()),
+                Node {
+                    width: Val::Px(TITLE_CONFIG.logo_size),
+                    height: Val::Px(TITLE_CONFIG.logo_size),
+                    ..default()
+                },
+            ));
+            for index in 0..TitleMenuItem::ALL.len() {
+                root.spawn((
+                    TitleMenuRow(index),
+                    Text::default(),
+                    TextFont::from_font_size(TITLE_CONFIG.item_font_size),
+                    TextColor(ITEM_COLOR),
+                ));
+            }
+            root.spawn((
+                TitleModeDescription,
+                Text::default(),
+                TextFont::from_font_size(TITLE_CONFIG.description_font_size),
+                TextColor(ITEM_COLOR),
+            ));
+            root.spawn((
+                Text::new("Arrows/D-pad: choose   Enter/A: select"),
+                TextFont::from_font_size(TITLE_CONFIG.hint_font_size),
+                TextColor(ITEM_COLOR),
+                Node {
+                    margin: UiRect::top(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+pub fn title_navigation_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut menu: ResMut<TitleMenu>,
+    mut rebind: ResMut<RebindScreen>,
+    mut audio_settings: ResMut<AudioSettingsScreen>,
+    mut next: ResMut<NextState<AppState>>,
+) {
+    // Controls and Audio open settings screens, which take over the same keys.
+    if rebind.open || audio_settings.open {
+        return;
+    }
+
+    let pressed = |key: KeyCode, button: GamepadButton| {
+        keyboard.just_pressed(key) || gamepads.iter().any(|pad| pad.just_pressed(button))
+    };
+
+    let count = TitleMenuItem::ALL.len();
+    if pressed(KeyCode::ArrowUp, GamepadButton::DPadUp) {
+        menu.selected = (menu.selected + count - 1) % count;
+    }
+    if pressed(KeyCode::ArrowDown, GamepadButton::DPadDown) {
+        menu.selected = (menu.selected + 1) % count;
+    }
+
+    let item = TitleMenuItem::ALL[menu.selected];
+    if item == TitleMenuItem::Mode {
+        if pressed(KeyCode::ArrowLeft, GamepadButton::DPadLeft) {
+            menu.mode = menu.mode.previous();
+        }
+        if pressed(KeyCode::ArrowRight, GamepadButton::DPadRight) {
+            menu.mode = menu.mode.next();
+        }
+    }
+
+    if !pressed(KeyCode::Enter, GamepadButton::South) {
+        return;
+    }
+
+    match item {
+        TitleMenuItem::Start => next.set(AppState::Playing),
+        TitleMenuItem::Mode => menu.mode = menu.mode.next(),
+        TitleMenuItem::Controls => rebind.request_open(),
+        TitleMenuItem::Audio => audio_settings.request_open(),
+    }
+}
+
+pub fn title_menu_text_system(
+    menu: Res<TitleMenu>,
+    mut rows: Query<(&TitleMenuRow, &mut Text, &mut TextColor), Without<TitleModeDescription>>,
+    mut description: Query<&mut Text, With<TitleModeDescription>>,
+) {
+    if !menu.is_changed() {
+        return;
+    }
+
+    for (row, mut text, mut color) in rows.iter_mut() {
+        text.0 = match TitleMenuItem::ALL[row.0] {
+            TitleMenuItem::Start => "Start".to_string(),
+            TitleMenuItem::Mode => format!("Mode: < {} >", menu.mode.label()),
+            TitleMenuItem::Controls => "Controls".to_string(),
+            TitleMenuItem::Audio => "Audio".to_string(),
+        };
+        color.0 = if row.0 == menu.selected {
+            SELECTED_ITEM_COLOR
+        } else {
+            ITEM_COLOR
+        };
+    }
+
+    for mut text in description.iter_mut() {
+        text.0 = menu.mode.description().to_string();
+    }
+}
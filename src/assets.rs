@@ -1,12 +1,17 @@
-use crate::constants::{CAMERA_OFFSET, WORLD_WIDTH, Z_FOREGROUND, Z_LEVEL, Z_PARALLAX_BACKGROUND};
+use crate::components::{LevelEntity, WorldBounds};
+use crate::constants::{CAMERA_OFFSET, Z_FOREGROUND, Z_LEVEL, Z_PARALLAX_BACKGROUND};
+use crate::weapon::WeaponKind;
+use bevy::asset::UntypedAssetId;
 use bevy::audio::AudioSource;
 use bevy::prelude::*;
 
-#[derive(Resource)]
+include!(concat!(env!("OUT_DIR"), "/level_image_chunks.rs"));
+
+/// Reflected so the loading screen can walk every handle without listing
+/// them again.
+#[derive(Resource, Reflect)]
 pub struct GameAssets {
-    pub level_chunks: Vec<Handle<Image>>,
     pub parallax_background: Handle<Image>,
-    pub foreground_chunks: Vec<Handle<Image>>,
     pub player_static: Handle<Image>,
     pub player_shooting: Handle<Image>,
     pub player_run_a: Handle<Image>,
@@ -19,6 +24,10 @@ pub struct GameAssets {
     pub player_hit: Handle<Image>,
     pub player_fall: Handle<Image>,
     pub ui_heart: Handle<Image>,
+    pub ui_coin: Handle<Image>,
+    pub ui_bonus: Handle<Image>,
+    pub ui_title: Handle<Image>,
+    pub ui_logo: Handle<Image>,
     pub player_run_up_a: Handle<Image>,
     pub player_run_up_b: Handle<Image>,
     pub player_run_up_c: Handle<Image>,
@@ -94,13 +103,17 @@ pub struct GameAssets {
     pub explosion_d_o: Handle<Image>,
     pub explosion_d_p: Handle<Image>,
     pub player_projectile: Handle<Image>,
+    pub player_projectile_spread: Handle<Image>,
+    pub player_projectile_laser: Handle<Image>,
+    pub player_projectile_rapid_fire: Handle<Image>,
+    pub player_projectile_homing: Handle<Image>,
+    pub player_projectile_flame: Handle<Image>,
     pub player_projectile_hit_a: Handle<Image>,
     pub player_projectile_hit_b: Handle<Image>,
     pub player_projectile_hit_c: Handle<Image>,
     pub player_shoot_flash: Handle<Image>,
     pub shoot_sfx: Handle<AudioSource>,
     pub player_hit_sfx: Handle<AudioSource>,
-    pub player_game_over_sfx: Handle<AudioSource>,
     pub enemy_shoot_sfx: Handle<AudioSource>,
     pub enemy_hit_sfx: Handle<AudioSource>,
     pub enemy_death_sfx: Handle<AudioSource>,
@@ -109,27 +122,12 @@ pub struct GameAssets {
     pub boss_projectile: Handle<Image>,
     pub enemy_explosion_sfx: Handle<AudioSource>,
     pub miniboss_explosion_sfx: Handle<AudioSource>,
+    pub coin_sfx: Handle<AudioSource>,
 }
 
 pub fn load_game_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let level_chunk_count: usize = env!("LEVEL_CHUNK_COUNT")
-        .parse()
-        .expect("LEVEL_CHUNK_COUNT must be a positive integer");
-    let foreground_chunk_count: usize = env!("FOREGROUND_CHUNK_COUNT")
-        .parse()
-        .expect("FOREGROUND_CHUNK_COUNT must be a positive integer");
-
-    let level_chunks: Vec<Handle<Image>> = (1..=level_chunk_count)
-        .map(|index| asset_server.load(format!("levels/level_part{index}.png")))
-        .collect();
-    let foreground_chunks: Vec<Handle<Image>> = (1..=foreground_chunk_count)
-        .map(|index| asset_server.load(format!("levels/foreground_part{index}.png")))
-        .collect();
-
     let game_assets = GameAssets {
-        level_chunks,
         parallax_background: asset_server.load("levels/background.png"),
-        foreground_chunks,
         player_static: asset_server.load("sprites/player_static.png"),
         player_shooting: asset_server.load("sprites/player_shooting.png"),
         player_run_a: asset_server.load("sprites/player_run_a.png"),
@@ -142,6 +140,10 @@ pub fn load_game_assets(mut commands: Commands, asset_server: Res<AssetServer>)
         player_hit: asset_server.load("sprites/player_hit.png"),
         player_fall: asset_server.load("sprites/player_fall.png"),
         ui_heart: asset_server.load("ui/heart.png"),
+        ui_coin: asset_server.load("ui/coin.png"),
+        ui_bonus: asset_server.load("ui/bonus.png"),
+        ui_title: asset_server.load("ui/title.png"),
+        ui_logo: asset_server.load("ui/logo.png"),
         player_run_up_a: asset_server.load("sprites/player_run_up_a.png"),
         player_run_up_b: asset_server.load("sprites/player_run_up_b.png"),
         player_run_up_c: asset_server.load("sprites/player_run_up_c.png"),
@@ -217,13 +219,17 @@ pub fn load_game_assets(mut commands: Commands, asset_server: Res<AssetServer>)
         explosion_d_o: asset_server.load("sprites/explosion_d_o.png"),
         explosion_d_p: asset_server.load("sprites/explosion_d_p.png"),
         player_projectile: asset_server.load("sprites/player_projectile.png"),
+        player_projectile_spread: asset_server.load("sprites/player_projectile_spread.png"),
+        player_projectile_laser: asset_server.load("sprites/player_projectile_laser.png"),
+        player_projectile_rapid_fire: asset_server.load("sprites/player_projectile_rapid_fire.png"),
+        player_projectile_homing: asset_server.load("sprites/player_projectile_homing.png"),
+        player_projectile_flame: asset_server.load("sprites/player_projectile_flame.png"),
         player_projectile_hit_a: asset_server.load("sprites/player_projectile_hit_a.png"),
         player_projectile_hit_b: asset_server.load("sprites/player_projectile_hit_b.png"),
         player_projectile_hit_c: asset_server.load("sprites/player_projectile_hit_c.png"),
         player_shoot_flash: asset_server.load("sprites/player_shoot.png"),
         shoot_sfx: asset_server.load("ost/shoot.ogg"),
         player_hit_sfx: asset_server.load("ost/player_hit.ogg"),
-    player_game_over_sfx: asset_server.load("ost/game_over.ogg"),
         enemy_shoot_sfx: asset_server.load("ost/enemy_gun_1.ogg"),
         enemy_hit_sfx: asset_server.load("ost/hit.ogg"),
         enemy_death_sfx: asset_server.load("ost/enemy_death.ogg"),
@@ -232,10 +238,80 @@ pub fn load_game_assets(mut commands: Commands, asset_server: Res<AssetServer>)
         boss_projectile: asset_server.load("sprites/boss_projectile.png"),
         enemy_explosion_sfx: asset_server.load("ost/enemy_explosion.ogg"),
         miniboss_explosion_sfx: asset_server.load("ost/miniboss_explosion.ogg"),
+        coin_sfx: asset_server.load("ost/coin.ogg"),
     };
     commands.insert_resource(game_assets);
 }
 
+impl GameAssets {
+    pub fn weapon_projectile(&self, kind: WeaponKind) -> &Handle<Image> {
+        match kind {
+            WeaponKind::Standard => &self.player_projectile,
+            WeaponKind::Spread => &self.player_projectile_spread,
+            WeaponKind::Laser => &self.player_projectile_laser,
+            WeaponKind::RapidFire => &self.player_projectile_rapid_fire,
+            WeaponKind::Homing => &self.player_projectile_homing,
+            WeaponKind::Flame => &self.player_projectile_flame,
+        }
+    }
+
+    pub fn untyped_ids(&self) -> Vec<UntypedAssetId> {
+        self.iter_fields()
+            .filter_map(|field| {
+                field
+                    .try_downcast_ref::<Handle<Image>>()
+                    .map(|handle| handle.id().untyped())
+                    .or_else(|| {
+                        field
+                            .try_downcast_ref::<Handle<AudioSource>>()
+                            .map(|handle| handle.id().untyped())
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Chunked level and foreground layers of the level currently being played.
+#[derive(Resource, Default)]
+pub struct LevelImages {
+    pub level_chunks: Vec<Handle<Image>>,
+    pub foreground_chunks: Vec<Handle<Image>>,
+}
+
+impl LevelImages {
+    pub fn load(asset_server: &AssetServer, background: &str, foreground: &str) -> Self {
+        Self {
+            level_chunks: load_image_chunks(asset_server, background),
+            foreground_chunks: load_image_chunks(asset_server, foreground),
+        }
+    }
+
+    pub fn untyped_ids(&self) -> impl Iterator<Item = UntypedAssetId> + '_ {
+        self.level_chunks
+            .iter()
+            .chain(&self.foreground_chunks)
+            .map(|handle| handle.id().untyped())
+    }
+}
+
+/// How many chunks `build.rs` split the level image `basename` into, or
+/// `None` if there is no such image in `levels/`.
+pub fn level_image_chunk_count(basename: &str) -> Option<usize> {
+    LEVEL_IMAGE_CHUNKS
+        .iter()
+        .find(|(name, _)| *name == basename)
+        .map(|(_, count)| *count)
+}
+
+fn load_image_chunks(asset_server: &AssetServer, basename: &str) -> Vec<Handle<Image>> {
+    let chunk_count = level_image_chunk_count(basename)
+        .expect("level files with unknown images fail to load");
+
+    (1..=chunk_count)
+        .map(|index| asset_server.load(format!("levels/{basename}_part{index}.png")))
+        .collect()
+}
+
 #[derive(Component)]
 pub struct LevelBackground;
 
@@ -252,27 +328,31 @@ pub struct LevelSegment(pub usize);
 pub struct ForegroundSegment(pub usize);
 
 pub fn setup_level_background(mut commands: Commands, game_assets: Res<GameAssets>) {
-    for (index, handle) in game_assets.level_chunks.iter().cloned().enumerate() {
+    commands.spawn((
+        Sprite::from_image(game_assets.parallax_background.clone()),
+        Transform::from_xyz(0.0, 0.0, Z_PARALLAX_BACKGROUND),
+        ParallaxBackground,
+    ));
+}
+
+pub fn spawn_level_layers(commands: &mut Commands, level_images: &LevelImages) {
+    for (index, handle) in level_images.level_chunks.iter().cloned().enumerate() {
         commands.spawn((
             Sprite::from_image(handle),
             Transform::from_xyz(0.0, 0.0, Z_LEVEL),
             LevelBackground,
             LevelSegment(index),
+            LevelEntity,
         ));
     }
 
-    commands.spawn((
-        Sprite::from_image(game_assets.parallax_background.clone()),
-        Transform::from_xyz(0.0, 0.0, Z_PARALLAX_BACKGROUND),
-        ParallaxBackground,
-    ));
-
-    for (index, handle) in game_assets.foreground_chunks.iter().cloned().enumerate() {
+    for (index, handle) in level_images.foreground_chunks.iter().cloned().enumerate() {
         commands.spawn((
             Sprite::from_image(handle),
             Transform::from_xyz(0.0, 0.0, Z_FOREGROUND),
             ForegroundLayer,
             ForegroundSegment(index),
+            LevelEntity,
         ));
     }
 }
@@ -282,10 +362,10 @@ pub fn position_level_background(
         Query<(&mut Transform, &LevelSegment), With<LevelBackground>>,
         Query<(&mut Transform, &ForegroundSegment), With<ForegroundLayer>>,
     )>,
-    game_assets: Res<GameAssets>,
+    level_images: Res<LevelImages>,
     images: Res<Assets<Image>>,
 ) {
-    if let Some(level_offsets) = compute_segment_offsets(&game_assets.level_chunks, &images) {
+    if let Some(level_offsets) = compute_segment_offsets(&level_images.level_chunks, &images) {
         for (mut transform, segment) in queries.p0().iter_mut() {
             if let Some(offset) = level_offsets.get(segment.0) {
                 transform.translation.x = *offset;
@@ -295,7 +375,7 @@ pub fn position_level_background(
         }
     }
 
-    if let Some(foreground_offsets) = compute_segment_offsets(&game_assets.foreground_chunks, &images) {
+    if let Some(foreground_offsets) = compute_segment_offsets(&level_images.foreground_chunks, &images) {
         for (mut transform, segment) in queries.p1().iter_mut() {
             if let Some(offset) = foreground_offsets.get(segment.0) {
                 transform.translation.x = *offset;
@@ -326,6 +406,7 @@ pub fn parallax_movement_system(
     mut parallax_query: Query<&mut Transform, With<ParallaxBackground>>,
     camera_query: Query<&Transform, (With<Camera>, Without<ParallaxBackground>)>,
     game_assets: Res<GameAssets>,
+    world: Res<WorldBounds>,
     images: Res<Assets<Image>>,
     mut last_cam_x: Local<Option<f32>>,
 ) {
@@ -344,9 +425,9 @@ pub fn parallax_movement_system(
             for mut transform in parallax_query.iter_mut() {
                 let background_width = background_image.width() as f32;
                 let start = background_width / 2.0 - CAMERA_OFFSET * 2.0;
-                let end = WORLD_WIDTH - background_width / 2.0 - CAMERA_OFFSET;
+                let end = world.width - background_width / 2.0 - CAMERA_OFFSET;
 
-                let level_movement_progress = camera_x / WORLD_WIDTH;
+                let level_movement_progress = camera_x / world.width;
                 let new_x = start + level_movement_progress * end;
                 if (transform.translation.x - new_x).abs() >= 0.5 {
                     transform.translation.x = new_x;
@@ -0,0 +1,174 @@
+use crate::replay::Replay;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+const ATTESTATION_MAGIC: &[u8; 4] = b"GDRA";
+const ATTESTATION_VERSION: u8 = 1;
+
+#[cfg(all(not(debug_assertions), not(gunday_attestation_key)))]
+compile_error!("release builds sign win codes; set GUNDAY_ATTESTATION_KEY at build time");
+
+/// Key win codes are signed with, set through `GUNDAY_ATTESTATION_KEY` at
+/// build time. Only debug builds may leave it unset and fall back to a public
+/// development key. It ships inside the game, so a code proves it came out of
+/// a build, not that nobody read the key out of it; servers that care should
+/// also ask for the replay and check it against `replay_hash`.
+pub const SIGNING_KEY: &[u8] = match option_env!("GUNDAY_ATTESTATION_KEY") {
+    Some(key) => key.as_bytes(),
+    None => b"gunday-development-key",
+};
+
+/// What a finished run claims: the RNG seed, how many simulation ticks it
+/// took, the final score and the SHA-256 of its `Replay::to_bytes`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RunAttestation {
+    pub seed: u64,
+    pub duration_ticks: u32,
+    pub score: u32,
+    pub replay_hash: [u8; 32],
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum AttestationError {
+    #[error("not a win code")]
+    Malformed,
+    #[error("unsupported win code version {0}")]
+    UnsupportedVersion(String),
+    #[error("win code signature does not match")]
+    BadSignature,
+}
+
+impl RunAttestation {
+    pub fn for_replay(replay: &Replay, score: u32) -> Self {
+        Self {
+            seed: replay.seed,
+            duration_ticks: replay.frames.len() as u32,
+            score,
+            replay_hash: Sha256::digest(replay.to_bytes()).into(),
+        }
+    }
+
+    /// Bytes the signature covers: magic, version, then the little-endian
+    /// seed, ticks and score followed by the replay hash.
+    pub fn message(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(53);
+        bytes.extend_from_slice(ATTESTATION_MAGIC);
+        bytes.push(ATTESTATION_VERSION);
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+        bytes.extend_from_slice(&self.duration_ticks.to_le_bytes());
+        bytes.extend_from_slice(&self.score.to_le_bytes());
+        bytes.extend_from_slice(&self.replay_hash);
+        bytes
+    }
+
+    /// `v1.<seed>.<ticks>.<score>.<replay hash>.<hmac>`, with the seed,
+    /// hash and HMAC-SHA256 in lowercase hex.
+    pub fn sign(&self, key: &[u8]) -> String {
+        let mac = self.mac(key).finalize().into_bytes();
+        format!(
+            "v{ATTESTATION_VERSION}.{:016x}.{}.{}.{}.{}",
+            self.seed,
+            self.duration_ticks,
+            self.score,
+            to_hex(&self.replay_hash),
+            to_hex(&mac),
+        )
+    }
+
+    fn mac(&self, key: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC takes keys of any length");
+        mac.update(&self.message());
+        mac
+    }
+}
+
+/// Checks a win code against `key` and returns what it attests to.
+pub fn verify_code(code: &str, key: &[u8]) -> Result<RunAttestation, AttestationError> {
+    let parts: Vec<&str> = code.trim().split('.').collect();
+    let [version, seed, ticks, score, replay_hash, mac] = parts[..] else {
+        return Err(AttestationError::Malformed);
+    };
+    if version != format!("v{ATTESTATION_VERSION}") {
+        return Err(AttestationError::UnsupportedVersion(version.to_string()));
+    }
+
+    let attestation = RunAttestation {
+        seed: u64::from_str_radix(seed, 16).map_err(|_| AttestationError::Malformed)?,
+        duration_ticks: ticks.parse().map_err(|_| AttestationError::Malformed)?,
+        score: score.parse().map_err(|_| AttestationError::Malformed)?,
+        replay_hash: from_hex(replay_hash).ok_or(AttestationError::Malformed)?,
+    };
+    let mac = from_hex(mac).ok_or(AttestationError::Malformed)?;
+
+    attestation
+        .mac(key)
+        .verify_slice(&mac)
+        .map_err(|_| AttestationError::BadSignature)?;
+
+    Ok(attestation)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn from_hex(text: &str) -> Option<[u8; 32]> {
+    if text.len() != 64 || !text.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    let mut bytes = [0; 32];
+    for (byte, pair) in bytes.iter_mut().zip(text.as_bytes().chunks_exact(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// Test vectors for servers implementing their own verifier.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::components::ActionFrame;
+
+    const CODE: &str = "v1.000000000000002a.5.12345.\
+        7fe5347e472553b36997dca57ca8d9e8aa2980cc7ea9f6264c217e8e03d6707e.\
+        7ba127b747cb1123a69b330d80181c453273e1b3cbd63d63c121848c78f6a3ad";
+
+    fn replay() -> Replay {
+        let mut frames = vec![ActionFrame::default(); 3];
+        frames.extend(
+            [ActionFrame {
+                buttons: ActionFrame::SHOOT,
+                stick: [127, -127],
+            }; 2],
+        );
+        Replay { seed: 42, frames }
+    }
+
+    #[test]
+    fn signs_known_run() {
+        let attestation = RunAttestation::for_replay(&replay(), 12345);
+        assert_eq!(attestation.sign(b"test-key"), CODE);
+        assert_eq!(verify_code(CODE, b"test-key"), Ok(attestation));
+    }
+
+    #[test]
+    fn rejects_tampered_codes() {
+        let higher_score = CODE.replace(".12345.", ".99999.");
+        assert_eq!(
+            verify_code(&higher_score, b"test-key"),
+            Err(AttestationError::BadSignature)
+        );
+        assert_eq!(
+            verify_code(CODE, b"other-key"),
+            Err(AttestationError::BadSignature)
+        );
+        assert_eq!(
+            verify_code(&CODE.replacen("v1", "v9", 1), b"test-key"),
+            Err(AttestationError::UnsupportedVersion("v9".to_string()))
+        );
+        assert_eq!(
+            verify_code("v1.2a.5", b"test-key"),
+            Err(AttestationError::Malformed)
+        );
+    }
+}
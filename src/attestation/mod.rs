@@ -0,0 +1,7 @@
+pub mod code;
+pub mod plugin;
+pub mod run_log;
+
+pub use code::{AttestationError, RunAttestation, SIGNING_KEY, verify_code};
+pub use plugin::AttestationPlugin;
+pub use run_log::sign_current_run;
@@ -0,0 +1,12 @@
+use super::run_log::{RunLog, log_run_input_system, reset_run_log_system};
+use bevy::prelude::*;
+
+pub struct AttestationPlugin;
+
+impl Plugin for AttestationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunLog>()
+            .add_systems(FixedUpdate, log_run_input_system)
+            .add_systems(Update, reset_run_log_system);
+    }
+}
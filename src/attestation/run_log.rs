@@ -0,0 +1,37 @@
+use super::code::{RunAttestation, SIGNING_KEY};
+use crate::player::components::{ActionFrame, PlayerActions};
+use crate::replay::Replay;
+use crate::restart::GameRestartRequested;
+use crate::rng::GameRng;
+use crate::score::Score;
+use bevy::prelude::*;
+
+/// Input of every simulation tick since the run started, whatever the
+/// source, so a finished run can be hashed like a recorded replay.
+#[derive(Resource, Default)]
+pub struct RunLog {
+    pub frames: Vec<ActionFrame>,
+}
+
+pub fn log_run_input_system(actions: Res<PlayerActions>, mut log: ResMut<RunLog>) {
+    log.frames.push(actions.frame);
+}
+
+pub fn reset_run_log_system(
+    mut log: ResMut<RunLog>,
+    mut restart: EventReader<GameRestartRequested>,
+) {
+    if restart.read().last().is_some() {
+        log.frames.clear();
+    }
+}
+
+/// Signed code for the run so far, sent to the page on a win.
+pub fn sign_current_run(world: &World) -> String {
+    let replay = Replay {
+        seed: world.resource::<GameRng>().seed(),
+        frames: world.resource::<RunLog>().frames.clone(),
+    };
+    let score = world.resource::<Score>().points;
+    RunAttestation::for_replay(&replay, score).sign(SIGNING_KEY)
+}
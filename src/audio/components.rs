@@ -0,0 +1,118 @@
+use super::config::{
+    CUE_SFX, EXPLOSION_SFX, HIT_SFX, IMPORTANT_SFX, PICKUP_SFX, SHOT_SFX, SfxCategoryConfig,
+};
+use bevy::audio::{AudioSource, PlaybackSettings};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// A group of sounds sharing a set of voices and the same mixing rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SfxCategory {
+    PlayerShoot,
+    PlayerHit,
+    EnemyShoot,
+    EnemyHit,
+    EnemyExplosion,
+    EnemyDeath,
+    EnemyTransform,
+    BossHit,
+    BossShot,
+    BossDefeat,
+    Pickup,
+}
+
+impl SfxCategory {
+    pub const ALL: [SfxCategory; 11] = [
+        SfxCategory::PlayerShoot,
+        SfxCategory::PlayerHit,
+        SfxCategory::EnemyShoot,
+        SfxCategory::EnemyHit,
+        SfxCategory::EnemyExplosion,
+        SfxCategory::EnemyDeath,
+        SfxCategory::EnemyTransform,
+        SfxCategory::BossHit,
+        SfxCategory::BossShot,
+        SfxCategory::BossDefeat,
+        SfxCategory::Pickup,
+    ];
+
+    pub fn config(self) -> SfxCategoryConfig {
+        match self {
+            SfxCategory::PlayerShoot | SfxCategory::EnemyShoot | SfxCategory::BossShot => SHOT_SFX,
+            SfxCategory::EnemyHit | SfxCategory::EnemyDeath | SfxCategory::EnemyTransform => {
+                HIT_SFX
+            }
+            SfxCategory::EnemyExplosion => EXPLOSION_SFX,
+            SfxCategory::Pickup => PICKUP_SFX,
+            SfxCategory::PlayerHit | SfxCategory::BossHit => IMPORTANT_SFX,
+            SfxCategory::BossDefeat => CUE_SFX,
+        }
+    }
+}
+
+/// Which category each game sound plays in, for `play_sfx_once`.
+#[derive(Resource, Clone, Copy)]
+pub struct SfxEmitters {
+    pub player_shoot: SfxCategory,
+    pub player_hit: SfxCategory,
+    pub enemy_shoot: SfxCategory,
+    pub enemy_hit: SfxCategory,
+    pub enemy_explosion: SfxCategory,
+    pub enemy_death: SfxCategory,
+    pub enemy_transform: SfxCategory,
+    pub boss_hit: SfxCategory,
+    pub boss_shot: SfxCategory,
+    pub boss_defeat: SfxCategory,
+    pub pickup: SfxCategory,
+}
+
+impl Default for SfxEmitters {
+    fn default() -> Self {
+        Self {
+            player_shoot: SfxCategory::PlayerShoot,
+            player_hit: SfxCategory::PlayerHit,
+            enemy_shoot: SfxCategory::EnemyShoot,
+            enemy_hit: SfxCategory::EnemyHit,
+            enemy_explosion: SfxCategory::EnemyExplosion,
+            enemy_death: SfxCategory::EnemyDeath,
+            enemy_transform: SfxCategory::EnemyTransform,
+            boss_hit: SfxCategory::BossHit,
+            boss_shot: SfxCategory::BossShot,
+            boss_defeat: SfxCategory::BossDefeat,
+            pickup: SfxCategory::Pickup,
+        }
+    }
+}
+
+#[derive(Event, Clone, Debug)]
+pub struct SfxRequest {
+    pub category: SfxCategory,
+    pub clip: Handle<AudioSource>,
+    pub settings: PlaybackSettings,
+}
+
+/// One entity that can play one sound at a time. It is playing while it
+/// has an `AudioPlayer`, which Bevy removes once the sound ends.
+#[derive(Component)]
+pub struct SfxVoice {
+    pub category: SfxCategory,
+    /// `Time<Real>` seconds the current sound started at.
+    pub started: f64,
+}
+
+/// Voice bookkeeping that outlives a frame. Pitch variation has its own
+/// RNG so sound never changes what `GameRng` hands to gameplay.
+#[derive(Resource)]
+pub struct SfxPool {
+    pub last_played: HashMap<AssetId<AudioSource>, f64>,
+    pub rng: fastrand::Rng,
+}
+
+impl Default for SfxPool {
+    fn default() -> Self {
+        Self {
+            last_played: HashMap::new(),
+            rng: fastrand::Rng::new(),
+        }
+    }
+}
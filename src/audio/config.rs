@@ -0,0 +1,64 @@
+#[derive(Debug, Clone, Copy)]
+pub struct SfxPoolConfig {
+    /// Voices playing at once across every category. Past this, a new sound
+    /// steals the oldest voice of equal or lower priority or is dropped.
+    pub max_voices: usize,
+}
+
+pub const SFX_POOL_CONFIG: SfxPoolConfig = SfxPoolConfig { max_voices: 24 };
+
+#[derive(Debug, Clone, Copy)]
+pub struct SfxCategoryConfig {
+    pub voices: usize,
+    /// Higher priorities win voices over lower ones.
+    pub priority: u8,
+    /// Seconds before the same clip can start again.
+    pub cooldown: f32,
+    /// Playback speed is randomised by up to this much either way.
+    pub pitch_variation: f32,
+}
+
+/// Gunfire, many at once and often.
+pub const SHOT_SFX: SfxCategoryConfig = SfxCategoryConfig {
+    voices: 4,
+    priority: 0,
+    cooldown: 0.03,
+    pitch_variation: 0.06,
+};
+
+pub const HIT_SFX: SfxCategoryConfig = SfxCategoryConfig {
+    voices: 4,
+    priority: 1,
+    cooldown: 0.05,
+    pitch_variation: 0.05,
+};
+
+pub const EXPLOSION_SFX: SfxCategoryConfig = SfxCategoryConfig {
+    voices: 4,
+    priority: 1,
+    cooldown: 0.04,
+    pitch_variation: 0.08,
+};
+
+pub const PICKUP_SFX: SfxCategoryConfig = SfxCategoryConfig {
+    voices: 3,
+    priority: 2,
+    cooldown: 0.02,
+    pitch_variation: 0.04,
+};
+
+/// Feedback on the player and boss that must not be lost in a fight.
+pub const IMPORTANT_SFX: SfxCategoryConfig = SfxCategoryConfig {
+    voices: 2,
+    priority: 2,
+    cooldown: 0.05,
+    pitch_variation: 0.0,
+};
+
+/// One-off moments such as game over or the boss going down.
+pub const CUE_SFX: SfxCategoryConfig = SfxCategoryConfig {
+    voices: 1,
+    priority: 3,
+    cooldown: 0.0,
+    pitch_variation: 0.0,
+};
@@ -0,0 +1,126 @@
+use crate::storage;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const MIXER_STORAGE_KEY: &str = "audio";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MixerBus {
+    Master,
+    Music,
+    Sfx,
+}
+
+impl MixerBus {
+    pub const ALL: [MixerBus; 3] = [MixerBus::Master, MixerBus::Music, MixerBus::Sfx];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MixerBus::Master => "Master",
+            MixerBus::Music => "Music",
+            MixerBus::Sfx => "Effects",
+        }
+    }
+}
+
+/// Linear bus volumes from 0 to 1. Music and sound effects play at their
+/// own bus times master, and not at all while muted. Saved once it stops
+/// changing.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioMixer {
+    pub master: f32,
+    pub music: f32,
+    pub sfx: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioMixer {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            music: 1.0,
+            sfx: 1.0,
+            muted: false,
+        }
+    }
+}
+
+impl AudioMixer {
+    /// The stored mixer, or the defaults if none was saved or it can't be read.
+    pub fn load() -> Self {
+        let Some(text) = storage::load(MIXER_STORAGE_KEY) else {
+            return Self::default();
+        };
+        match ron::from_str::<AudioMixer>(&text) {
+            Ok(saved) => saved,
+            Err(err) => {
+                warn!("ignoring saved audio settings: {err}");
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let result = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| err.to_string())
+            .and_then(|text| {
+                storage::save(MIXER_STORAGE_KEY, &text).map_err(|err| err.to_string())
+            });
+        if let Err(err) = result {
+            error!("could not save audio settings: {err}");
+        }
+    }
+
+    pub fn volume(&self, bus: MixerBus) -> f32 {
+        match bus {
+            MixerBus::Master => self.master,
+            MixerBus::Music => self.music,
+            MixerBus::Sfx => self.sfx,
+        }
+    }
+
+    /// Clamps to 0..=1. NaN and infinity leave the bus as it was.
+    pub fn set_volume(&mut self, bus: MixerBus, volume: f32) {
+        if !volume.is_finite() {
+            return;
+        }
+        let volume = volume.clamp(0.0, 1.0);
+        match bus {
+            MixerBus::Master => self.master = volume,
+            MixerBus::Music => self.music = volume,
+            MixerBus::Sfx => self.sfx = volume,
+        }
+    }
+
+    /// The master bus as played, silent while muted.
+    pub fn master_gain(&self) -> f32 {
+        if self.muted { 0.0 } else { self.master }
+    }
+
+    pub fn music_gain(&self) -> f32 {
+        self.gain(MixerBus::Music)
+    }
+
+    pub fn sfx_gain(&self) -> f32 {
+        self.gain(MixerBus::Sfx)
+    }
+
+    fn gain(&self, bus: MixerBus) -> f32 {
+        self.master_gain() * self.volume(bus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignores_non_finite_volume() {
+        let mut mixer = AudioMixer::default();
+        mixer.set_volume(MixerBus::Master, 0.5);
+        mixer.set_volume(MixerBus::Master, f32::NAN);
+        mixer.set_volume(MixerBus::Master, f32::INFINITY);
+        assert_eq!(mixer.volume(MixerBus::Master), 0.5);
+    }
+}
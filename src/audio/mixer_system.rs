@@ -0,0 +1,56 @@
+use super::mixer::{AudioMixer, MixerBus};
+use crate::soundtrack::SoundtrackController;
+use bevy::prelude::*;
+
+/// How long the mixer has to stay put before it is saved, so holding a
+/// slider key doesn't write storage every frame.
+const SAVE_DELAY_SECS: f32 = 0.5;
+
+/// Counts down to saving a changed mixer. Runs on real time, since the
+/// settings screen pauses the virtual clock.
+#[derive(Resource, Default)]
+pub(crate) struct PendingMixerSave(Option<Timer>);
+
+/// Sound effects read the mixer as they start; the soundtrack keeps playing,
+/// so its bus gains are pushed to the controller instead. Mute rides on the
+/// master bus there, apart from the music bus the soundtrack ducks.
+pub(crate) fn apply_mixer_system(
+    mixer: Res<AudioMixer>,
+    mut controller: ResMut<SoundtrackController>,
+    mut pending: ResMut<PendingMixerSave>,
+) {
+    if !mixer.is_changed() {
+        return;
+    }
+
+    controller.set_music_gain(mixer.volume(MixerBus::Music));
+    controller.set_master_gain(mixer.master_gain());
+    if !mixer.is_added() {
+        pending.0 = Some(Timer::from_seconds(SAVE_DELAY_SECS, TimerMode::Once));
+    }
+}
+
+pub(crate) fn save_mixer_system(
+    mixer: Res<AudioMixer>,
+    time: Res<Time<Real>>,
+    mut pending: ResMut<PendingMixerSave>,
+) {
+    let Some(timer) = pending.0.as_mut() else {
+        return;
+    };
+    if timer.tick(time.delta()).finished() {
+        mixer.save();
+        pending.0 = None;
+    }
+}
+
+/// Catches a change made within `SAVE_DELAY_SECS` of quitting.
+pub(crate) fn save_mixer_on_exit(
+    exit: EventReader<AppExit>,
+    mixer: Res<AudioMixer>,
+    mut pending: ResMut<PendingMixerSave>,
+) {
+    if !exit.is_empty() && pending.0.take().is_some() {
+        mixer.save();
+    }
+}
@@ -0,0 +1,13 @@
+pub mod components;
+pub mod config;
+pub mod mixer;
+pub mod mixer_system;
+pub mod plugin;
+pub mod pool_system;
+pub mod settings_screen;
+
+pub use components::{SfxCategory, SfxEmitters};
+pub use mixer::{AudioMixer, MixerBus};
+pub use plugin::GameAudioPlugin;
+pub use pool_system::{play_sfx, play_sfx_once};
+pub use settings_screen::AudioSettingsScreen;
@@ -0,0 +1,31 @@
+use super::components::{SfxEmitters, SfxPool, SfxRequest};
+use super::mixer::AudioMixer;
+use super::mixer_system::{
+    PendingMixerSave, apply_mixer_system, save_mixer_on_exit, save_mixer_system,
+};
+use super::pool_system::{play_sfx_requests_system, spawn_sfx_voices};
+use super::settings_screen::AudioSettingsScreenPlugin;
+use bevy::prelude::*;
+
+/// Sound effect voices, the mixer and its settings screen.
+pub struct GameAudioPlugin;
+
+impl Plugin for GameAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(AudioMixer::load())
+            .init_resource::<PendingMixerSave>()
+            .init_resource::<SfxEmitters>()
+            .init_resource::<SfxPool>()
+            .add_event::<SfxRequest>()
+            .add_plugins(AudioSettingsScreenPlugin)
+            .add_systems(Startup, spawn_sfx_voices)
+            .add_systems(
+                Update,
+                (
+                    play_sfx_requests_system,
+                    (apply_mixer_system, save_mixer_system).chain(),
+                ),
+            )
+            .add_systems(Last, save_mixer_on_exit);
+    }
+}
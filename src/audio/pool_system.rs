@@ -0,0 +1,152 @@
+use super::components::{SfxCategory, SfxPool, SfxRequest, SfxVoice};
+use super::config::SFX_POOL_CONFIG;
+use super::mixer::AudioMixer;
+use bevy::audio::{
+    AudioPlayer, AudioSink, AudioSource, PlaybackSettings, SpatialAudioSink, Volume,
+};
+use bevy::prelude::*;
+
+pub fn play_sfx(
+    commands: &mut Commands,
+    category: SfxCategory,
+    clip: Handle<AudioSource>,
+    settings: PlaybackSettings,
+) {
+    commands.send_event(SfxRequest {
+        category,
+        clip,
+        settings,
+    });
+}
+
+pub fn play_sfx_once(commands: &mut Commands, category: SfxCategory, clip: Handle<AudioSource>) {
+    play_sfx(commands, category, clip, PlaybackSettings::REMOVE);
+}
+
+pub fn spawn_sfx_voices(mut commands: Commands) {
+    for category in SfxCategory::ALL {
+        for index in 0..category.config().voices {
+            commands.spawn((
+                Name::new(format!("sfx: {category:?} {index}")),
+                SfxVoice {
+                    category,
+                    started: 0.0,
+                },
+            ));
+        }
+    }
+}
+
+struct VoiceSlot {
+    entity: Entity,
+    category: SfxCategory,
+    playing: bool,
+    started: f64,
+}
+
+/// Hands each request a voice of its category. When the category is full its
+/// oldest voice is cut; when the whole pool is full the oldest voice of equal
+/// or lower priority is, and if there is none the request is dropped.
+pub fn play_sfx_requests_system(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mixer: Res<AudioMixer>,
+    mut requests: EventReader<SfxRequest>,
+    mut pool: ResMut<SfxPool>,
+    voices: Query<(Entity, &SfxVoice, Has<AudioPlayer<AudioSource>>)>,
+) {
+    let mut pending: Vec<&SfxRequest> = requests.read().collect();
+    if pending.is_empty() {
+        return;
+    }
+    pending.sort_by_key(|request| std::cmp::Reverse(request.category.config().priority));
+
+    let now = time.elapsed_secs_f64();
+    let mut slots: Vec<VoiceSlot> = voices
+        .iter()
+        .map(|(entity, voice, playing)| VoiceSlot {
+            entity,
+            category: voice.category,
+            playing,
+            started: voice.started,
+        })
+        .collect();
+
+    for request in pending {
+        let config = request.category.config();
+        let clip = request.clip.id();
+        if pool
+            .last_played
+            .get(&clip)
+            .is_some_and(|last| now - last < f64::from(config.cooldown))
+        {
+            continue;
+        }
+
+        let Some((index, stolen)) = pick_voice(&slots, request.category, config.priority) else {
+            continue;
+        };
+        if let Some(stolen) = stolen {
+            stop_voice(&mut commands, slots[stolen].entity);
+            slots[stolen].playing = false;
+        }
+
+        let slot = &mut slots[index];
+        if slot.playing {
+            stop_voice(&mut commands, slot.entity);
+        }
+        slot.playing = true;
+        slot.started = now;
+
+        let variation = config.pitch_variation;
+        let speed = request.settings.speed * (1.0 + (pool.rng.f32() * 2.0 - 1.0) * variation);
+        let volume = request.settings.volume * Volume::Linear(mixer.sfx_gain());
+        commands.entity(slot.entity).insert((
+            SfxVoice {
+                category: request.category,
+                started: now,
+            },
+            AudioPlayer::new(request.clip.clone()),
+            request.settings.with_speed(speed).with_volume(volume),
+        ));
+        pool.last_played.insert(clip, now);
+    }
+}
+
+/// The voice to play on, plus a voice of another category to stop first
+/// when the pool is full.
+fn pick_voice(
+    slots: &[VoiceSlot],
+    category: SfxCategory,
+    priority: u8,
+) -> Option<(usize, Option<usize>)> {
+    let playing = slots.iter().filter(|slot| slot.playing).count();
+    let free = slots
+        .iter()
+        .position(|slot| slot.category == category && !slot.playing);
+
+    match free {
+        Some(index) if playing < SFX_POOL_CONFIG.max_voices => Some((index, None)),
+        Some(index) => oldest(slots, |slot| slot.category.config().priority <= priority)
+            .map(|stolen| (index, Some(stolen))),
+        None => oldest(slots, |slot| slot.category == category).map(|index| (index, None)),
+    }
+}
+
+fn oldest(slots: &[VoiceSlot], filter: impl Fn(&VoiceSlot) -> bool) -> Option<usize> {
+    slots
+        .iter()
+        .enumerate()
+        .filter(|(_, slot)| slot.playing && filter(slot))
+        .min_by(|(_, a), (_, b)| a.started.total_cmp(&b.started))
+        .map(|(index, _)| index)
+}
+
+fn stop_voice(commands: &mut Commands, voice: Entity) {
+    commands.entity(voice).remove::<(
+        AudioPlayer<AudioSource>,
+        PlaybackSettings,
+        AudioSink,
+        SpatialAudioSink,
+    )>();
+}
@@ -0,0 +1,230 @@
+use super::mixer::{AudioMixer, MixerBus};
+use crate::player::rebind_screen::RebindScreen;
+use bevy::prelude::*;
+
+/// Opened with F2 or from the title and pause menus. Gameplay is frozen
+/// while it is up; the mixer saves itself once it stops changing.
+#[derive(Resource, Default)]
+pub struct AudioSettingsScreen {
+    pub open: bool,
+    selected: usize,
+    open_requested: bool,
+    close_requested: bool,
+    /// Whether opening the screen is what paused the game.
+    paused_time: bool,
+}
+
+impl AudioSettingsScreen {
+    /// Opens the screen on the next frame, e.g. from the pause menu.
+    pub fn request_open(&mut self) {
+        if !self.open {
+            self.open_requested = true;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AudioSettingsRow {
+    Bus(MixerBus),
+    Mute,
+    Back,
+}
+
+const ROWS: [AudioSettingsRow; 5] = [
+    AudioSettingsRow::Bus(MixerBus::Master),
+    AudioSettingsRow::Bus(MixerBus::Music),
+    AudioSettingsRow::Bus(MixerBus::Sfx),
+    AudioSettingsRow::Mute,
+    AudioSettingsRow::Back,
+];
+
+#[derive(Component)]
+struct AudioSettingsRoot;
+
+#[derive(Component)]
+struct AudioSettingsRowText(usize);
+
+const VOLUME_STEP: f32 = 0.1;
+const ROW_COLOR: Color = Color::srgb(0.7, 0.7, 0.7);
+const SELECTED_ROW_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+pub struct AudioSettingsScreenPlugin;
+
+impl Plugin for AudioSettingsScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AudioSettingsScreen>().add_systems(
+            Update,
+            (
+                toggle_audio_settings_screen,
+                audio_settings_navigation_system.run_if(audio_settings_screen_open),
+                audio_settings_text_system.run_if(audio_settings_screen_open),
+            )
+                .chain(),
+        );
+    }
+}
+
+fn audio_settings_screen_open(screen: Res<AudioSettingsScreen>) -> bool {
+    screen.open
+}
+
+fn toggle_audio_settings_screen(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut screen: ResMut<AudioSettingsScreen>,
+    rebind: Res<RebindScreen>,
+    mut time: ResMut<Time<Virtual>>,
+    roots: Query<Entity, With<AudioSettingsRoot>>,
+) {
+    let toggled =
+        keyboard.just_pressed(KeyCode::F2) || screen.open_requested || screen.close_requested;
+    if !toggled {
+        return;
+    }
+
+    if screen.open {
+        for root in roots.iter() {
+            commands.entity(root).despawn();
+        }
+        // Leave the game paused if a menu was already holding it.
+        if screen.paused_time {
+            time.unpause();
+        }
+        *screen = AudioSettingsScreen::default();
+        return;
+    }
+
+    // The rebind screen owns the same keys while it is up.
+    if rebind.open {
+        screen.open_requested = false;
+        return;
+    }
+
+    screen.open = true;
+    screen.open_requested = false;
+    screen.paused_time = !time.is_paused();
+    time.pause();
+    spawn_audio_settings_screen(&mut commands);
+}
+
+fn spawn_audio_settings_screen(commands: &mut Commands) {
+    commands
+        .spawn((
+            AudioSettingsRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            GlobalZIndex(10),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("AUDIO"),
+                TextFont::from_font_size(28.0),
+                Node {
+                    margin: UiRect::bottom(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+            for index in 0..ROWS.len() {
+                root.spawn((
+                    AudioSettingsRowText(index),
+                    Text::default(),
+                    TextFont::from_font_size(18.0),
+                    TextColor(ROW_COLOR),
+                ));
+            }
+            root.spawn((
+                Text::new("Left/Right: adjust   Enter/A: toggle   F2: close"),
+                TextFont::from_font_size(14.0),
+                TextColor(ROW_COLOR),
+                Node {
+                    margin: UiRect::top(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn audio_settings_navigation_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut screen: ResMut<AudioSettingsScreen>,
+    mut mixer: ResMut<AudioMixer>,
+) {
+    let pressed = |key: KeyCode, button: GamepadButton| {
+        keyboard.just_pressed(key) || gamepads.iter().any(|pad| pad.just_pressed(button))
+    };
+
+    let count = ROWS.len();
+    if pressed(KeyCode::ArrowUp, GamepadButton::DPadUp) {
+        screen.selected = (screen.selected + count - 1) % count;
+    }
+    if pressed(KeyCode::ArrowDown, GamepadButton::DPadDown) {
+        screen.selected = (screen.selected + 1) % count;
+    }
+
+    match ROWS[screen.selected] {
+        AudioSettingsRow::Bus(bus) => {
+            let mut step = 0.0;
+            if pressed(KeyCode::ArrowLeft, GamepadButton::DPadLeft) {
+                step -= VOLUME_STEP;
+            }
+            if pressed(KeyCode::ArrowRight, GamepadButton::DPadRight) {
+                step += VOLUME_STEP;
+            }
+            if step != 0.0 {
+                // Snap to whole steps so repeated presses land on 0 and 1.
+                let volume = ((mixer.volume(bus) + step) / VOLUME_STEP).round() * VOLUME_STEP;
+                mixer.set_volume(bus, volume);
+            }
+        }
+        AudioSettingsRow::Mute => {
+            if pressed(KeyCode::Enter, GamepadButton::South)
+                || pressed(KeyCode::ArrowLeft, GamepadButton::DPadLeft)
+                || pressed(KeyCode::ArrowRight, GamepadButton::DPadRight)
+            {
+                mixer.muted = !mixer.muted;
+            }
+        }
+        AudioSettingsRow::Back => {
+            if pressed(KeyCode::Enter, GamepadButton::South) {
+                screen.close_requested = true;
+            }
+        }
+    }
+}
+
+fn audio_settings_text_system(
+    screen: Res<AudioSettingsScreen>,
+    mixer: Res<AudioMixer>,
+    mut rows: Query<(&AudioSettingsRowText, &mut Text, &mut TextColor)>,
+) {
+    if !screen.is_changed() && !mixer.is_changed() {
+        return;
+    }
+
+    for (row, mut text, mut color) in rows.iter_mut() {
+        text.0 = match ROWS[row.0] {
+            AudioSettingsRow::Bus(bus) => {
+                let percent = (mixer.volume(bus) * 100.0).round();
+                format!("{}: < {percent}% >", bus.label())
+            }
+            AudioSettingsRow::Mute => {
+                format!("Mute: {}", if mixer.muted { "On" } else { "Off" })
+            }
+            AudioSettingsRow::Back => "Back".to_string(),
+        };
+        color.0 = if row.0 == screen.selected {
+            SELECTED_ROW_COLOR
+        } else {
+            ROW_COLOR
+        };
+    }
+}
@@ -1,9 +1,13 @@
 use super::components::*;
 use super::config::BOSS_SETTINGS;
 use super::util::{approach_angle, shortest_angle_diff};
+use crate::app_state::RunFinished;
 use crate::assets::GameAssets;
 use crate::audio::{SfxEmitters, play_sfx_once};
+use crate::bridge::send_run_result;
 use crate::effects::explosion_anim::{spawn_explosion_c, spawn_explosion_d};
+use crate::level::{Campaign, LevelCompleted};
+use crate::soundtrack::{Stinger, play_stinger};
 use crate::systems::PlayerControl;
 use bevy::prelude::*;
 
@@ -28,6 +32,8 @@ pub fn boss_explosion_system(
     mut q: Query<(Entity, &mut BossExplodingState, &BossParts, &Transform), With<Boss>>,
     mut tf_q: Query<(&GlobalTransform, &mut Transform), Without<BossExplodingState>>,
     mut control: Option<ResMut<PlayerControl>>,
+    campaign: Res<Campaign>,
+    mut level_completed: EventWriter<LevelCompleted>,
 ) {
     let dt = time.delta_secs();
     for (e, mut ex, parts, root_transform) in q.iter_mut() {
@@ -143,9 +149,7 @@ pub fn boss_explosion_system(
         }
 
         if !ex.win_started && ex.timer >= BOSS_SETTINGS.explosion.win_start_delay {
-            if let Some(a) = &audio {
-                play_sfx_once(&mut commands, emitters.boss_win, a.win.clone());
-            }
+            play_stinger(&mut commands, Stinger::Win);
             ex.win_started = true;
             ex.win_timer = 0.0;
         }
@@ -153,12 +157,13 @@ pub fn boss_explosion_system(
             ex.win_timer += dt;
             if ex.win_timer >= BOSS_SETTINGS.explosion.win_exit_delay && !ex.result_sent {
                 ex.result_sent = true;
-                #[cfg(target_arch = "wasm32")]
-                {
-                    crate::systems::browser_events::send_game_result(true);
-                }
                 commands.entity(e).despawn();
-                commands.trigger(bevy::app::AppExit::Success);
+                if !campaign.is_final_level() {
+                    level_completed.write(LevelCompleted);
+                    continue;
+                }
+                commands.queue(|world: &mut World| send_run_result(world, true));
+                commands.send_event(RunFinished { win: true });
             }
         }
     }
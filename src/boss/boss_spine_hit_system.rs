@@ -3,7 +3,8 @@ use super::config::BOSS_SETTINGS;
 use super::events::*;
 use crate::assets::GameAssets;
 use crate::audio::{SfxEmitters, play_sfx_once};
-use crate::constants::{PROJECTILE_SIZE, Z_PROJECTILES};
+use crate::components::Health;
+use crate::constants::Z_PROJECTILES;
 use crate::effects::explosion_anim::spawn_explosion_c;
 use crate::projectile::components::{PlayerProjectile, Projectile, swept_projectile_hit_center};
 use bevy::prelude::*;
@@ -19,12 +20,15 @@ pub fn boss_spine_hit_system(
         &mut BossStage,
         &BossParts,
     )>,
-    mut spine_q: Query<(
-        &mut BossSpine,
-        &GlobalTransform,
-        &Transform,
-        Option<&mut BossSpineHitAnimation>,
-    )>,
+    mut spine_q: Query<
+        (
+            &mut Health,
+            &GlobalTransform,
+            &Transform,
+            Option<&mut BossSpineHitAnimation>,
+        ),
+        With<BossSpine>,
+    >,
     mut shooting_q: Query<&mut BossStage1ShootingState, With<Boss>>,
     cannon_state_q: Query<&BossGunRotation>,
     projectiles: Query<
@@ -37,7 +41,7 @@ pub fn boss_spine_hit_system(
         let Some(spine_e) = parts.get(BossPartKind::Spine) else {
             continue;
         };
-        let Ok((mut spine, spine_gtf, spine_tf, anim_opt)) = spine_q.get_mut(spine_e) else {
+        let Ok((mut health, spine_gtf, spine_tf, anim_opt)) = spine_q.get_mut(spine_e) else {
             continue;
         };
         let size = BOSS_SETTINGS.spine.size;
@@ -47,13 +51,13 @@ pub fn boss_spine_hit_system(
             if swept_projectile_hit_center(
                 projectile.previous_translation,
                 proj_tf.translation.truncate(),
-                Vec2::splat(PROJECTILE_SIZE),
+                projectile.size,
                 min,
                 size,
             )
             .is_some()
             {
-                spine.hp = spine.hp.saturating_sub(1);
+                health.apply(projectile.damage);
                 commands.entity(proj_e).despawn();
                 play_sfx_once(&mut commands, emitters.boss_hit, audio.hit.clone());
                 let current_rotation = spine_tf.rotation.to_euler(EulerRot::XYZ).2;
@@ -84,7 +88,7 @@ pub fn boss_spine_hit_system(
                         shooting_state.target = target;
                     }
                 }
-                if spine.hp == 0 {
+                if health.is_depleted() {
                     let burst_total = BOSS_SETTINGS.transition.explosion_burst_count.max(1);
                     let interval = BOSS_SETTINGS.transition.explosion_interval.max(0.01);
                     let mut pos = spine_gtf.translation();
@@ -2,6 +2,7 @@ use super::components::*;
 use super::config::BOSS_SETTINGS;
 use crate::components::MainCamera;
 use crate::constants::{GROUND_RECT_HEIGHT, SCREEN_HEIGHT, SCREEN_WIDTH};
+use crate::rng::GameRng;
 use bevy::prelude::*;
 
 pub fn boss_stage1_movement_system(
@@ -16,6 +17,7 @@ pub fn boss_stage1_movement_system(
         With<Boss>,
     >,
     camera_q: Query<&Transform, (With<MainCamera>, Without<Boss>)>,
+    mut rng: ResMut<GameRng>,
 ) {
     let camera_x = camera_q
         .iter()
@@ -41,7 +43,7 @@ pub fn boss_stage1_movement_system(
             let current = tf.translation.truncate();
             let nearest = nearest_anchor_index(current, &anchors);
             let others = [(nearest + 1) % 3, (nearest + 2) % 3];
-            let chosen_idx = if fastrand::bool() {
+            let chosen_idx = if rng.bool() {
                 others[0]
             } else {
                 others[1]
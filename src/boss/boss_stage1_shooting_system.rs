@@ -5,6 +5,7 @@ use crate::assets::GameAssets;
 use crate::audio::{SfxEmitters, play_sfx_once};
 use crate::player::components::Player;
 use crate::projectile::projectile_spawning_system::spawn_boss_projectile;
+use crate::rng::GameRng;
 use bevy::prelude::*;
 
 pub fn boss_stage1_shooting_system(
@@ -28,6 +29,7 @@ pub fn boss_stage1_shooting_system(
         Query<&GlobalTransform, With<BossCannon>>,
         Query<(&mut Transform, &mut BossGunRotation), (With<BossCannon>, Without<Boss>)>,
     )>,
+    mut rng: ResMut<GameRng>,
 ) {
     let Ok(player_gtf) = player_q.single() else {
         return;
@@ -156,7 +158,7 @@ pub fn boss_stage1_shooting_system(
                         shooting.shoot_timer = BOSS_SETTINGS.stage1.shooting.shoot_cooldown;
                         shooting.aim_cooldown = 0.0;
 
-                        if fastrand::f32() < 0.5 {
+                        if rng.f32() < 0.5 {
                             movement.pending_move_request = true;
                         }
                     } else {
@@ -124,7 +124,7 @@ pub fn boss_transition_to_stage2_update_system(
                 trans.downed_wait -= time.delta_secs();
             }
             if trans.downed_wait <= 0.0 {
-                let result = controller.request_track_set(TrackSetName::BossStage2);
+                let result = controller.request_track_set(TrackSetName::BOSS_STAGE_2);
                 trans.phase = TransitionPhase::AwaitStage2Music;
                 trans.pending_music_eta = result.eta_seconds.unwrap_or(0.0).max(0.0);
             }
@@ -1,8 +1,10 @@
 use super::config::BOSS_SETTINGS;
+use crate::interpolation::TransformInterpolation;
 use bevy::prelude::*;
 use std::collections::HashMap;
 
 #[derive(Component)]
+#[require(TransformInterpolation)]
 pub struct Boss;
 
 #[derive(Component)]
@@ -12,17 +14,7 @@ pub struct BossTorso;
 pub struct BossHead;
 
 #[derive(Component)]
-pub struct BossSpine {
-    pub hp: u8,
-}
-
-impl Default for BossSpine {
-    fn default() -> Self {
-        Self {
-            hp: BOSS_SETTINGS.stage1.spine_hp,
-        }
-    }
-}
+pub struct BossSpine;
 
 #[derive(Component)]
 pub struct BossSpineHitAnimation {
@@ -55,7 +47,6 @@ pub struct BossAudio {
     pub hit: Handle<AudioSource>,
     pub shot: Handle<AudioSource>,
     pub defeat: Handle<AudioSource>,
-    pub win: Handle<AudioSource>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -146,7 +137,6 @@ impl Default for BossStage2Pose {
 #[derive(Component)]
 pub struct BossStage2State {
     pub crawl_speed: f32,
-    pub head_hp: u8,
     pub crawl_timer: f32,
 }
 
@@ -154,7 +144,6 @@ impl Default for BossStage2State {
     fn default() -> Self {
         Self {
             crawl_speed: BOSS_SETTINGS.stage2.crawl_speed,
-            head_hp: BOSS_SETTINGS.stage2.head_hp,
             crawl_timer: 0.0,
         }
     }
@@ -192,6 +181,7 @@ impl Default for BossExplodingState {
 }
 
 #[derive(Component)]
+#[require(TransformInterpolation)]
 pub struct ExplodingPart {
     pub velocity: Vec2,
     pub angular_velocity: f32,
@@ -289,6 +279,7 @@ pub struct BossTransitionExplosionQueue {
 }
 
 #[derive(Component)]
+#[require(TransformInterpolation)]
 pub struct DetachedCannon {
     pub velocity: Vec2,
     pub angular_velocity: f32,
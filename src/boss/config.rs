@@ -19,7 +19,7 @@ pub struct BossSettings {
 
 #[derive(Debug, Clone, Copy)]
 pub struct Stage1Settings {
-    pub spine_hp: u8,
+    pub spine_hp: u32,
     pub hover_ground_offset: f32,
     pub wave_frequency: f32,
     pub amplitude_lerp_speed: f32,
@@ -52,7 +52,7 @@ pub struct Stage1ShootingSettings {
 #[derive(Debug, Clone, Copy)]
 pub struct Stage2Settings {
     pub crawl_speed: f32,
-    pub head_hp: u8,
+    pub head_hp: u32,
     pub arm_swing_amplitude: f32,
     pub arm_swing_frequency: f32,
     pub arm_base_shift_deg: f32,
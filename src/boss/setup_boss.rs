@@ -1,5 +1,6 @@
 use super::components::*;
 use super::config::BOSS_SETTINGS;
+use crate::components::Health;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
 
@@ -7,12 +8,10 @@ pub fn load_boss_audio(mut commands: Commands, assets: Res<AssetServer>) {
     let hit: Handle<AudioSource> = assets.load("ost/hit.ogg");
     let shot: Handle<AudioSource> = assets.load("ost/boss_shot.ogg");
     let defeat: Handle<AudioSource> = assets.load("ost/boss_defeat.ogg");
-    let win: Handle<AudioSource> = assets.load("ost/win.ogg");
     commands.insert_resource(BossAudio {
         hit,
         shot,
         defeat,
-        win,
     });
 }
 
@@ -116,6 +115,7 @@ pub fn spawn_boss(
                             BOSS_SETTINGS.head.neutral_angle_deg.to_radians(),
                         )),
                     BossHead,
+                    Health::new(BOSS_SETTINGS.stage2.head_hp),
                 ))
                 .id();
 
@@ -156,7 +156,8 @@ pub fn spawn_boss(
                     ..Default::default()
                 },
                 Transform::from_translation(spine_pos),
-                BossSpine::default(),
+                BossSpine,
+                Health::new(BOSS_SETTINGS.stage1.spine_hp),
             ));
 
             spine_ec.with_children(|spine_parent| {
@@ -36,9 +36,9 @@ impl Plugin for BossPlugin {
         app.add_event::<BossStageTransitionEvent>()
             .add_event::<BossDefeatedEvent>()
             .add_systems(Startup, super::setup_boss::load_boss_audio)
-            .add_systems(Update, super::spawn_system::boss_spawn_system)
+            .add_systems(FixedUpdate, super::spawn_system::boss_spawn_system)
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     boss_stage1_movement_system
                         .run_if(boss_in_stage(BossStageKind::Stage1)),
@@ -56,7 +56,7 @@ impl Plugin for BossPlugin {
                 ),
             )
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     boss_arm_transition_pose_system
                         .run_if(boss_in_stage(BossStageKind::TransitionToStage2)),
@@ -84,7 +84,7 @@ impl Plugin for BossPlugin {
                 ),
             )
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     boss_stage2_movement_system.run_if(boss_in_stage(BossStageKind::Stage2)),
                     boss_stage2_animation_system
@@ -0,0 +1,37 @@
+use super::event::BridgeEvent;
+use bevy::prelude::*;
+
+/// Where bridge events go: `window` on the web. Native builds have no page,
+/// so events are only logged at debug level.
+#[derive(Resource, Default)]
+pub struct EventBridge;
+
+impl EventBridge {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn send(&self, event: BridgeEvent) {
+        debug!("{} {event:?}", event.dom_name());
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn send(&self, event: BridgeEvent) {
+        use web_sys::{CustomEvent, CustomEventInit, window};
+
+        let Some(window) = window() else {
+            return;
+        };
+        let detail = match serde_wasm_bindgen::to_value(&event) {
+            Ok(detail) => detail,
+            Err(err) => {
+                warn!("could not serialize {}: {err}", event.dom_name());
+                return;
+            }
+        };
+
+        let init = CustomEventInit::new();
+        init.set_bubbles(true);
+        init.set_detail(&detail);
+        if let Ok(custom) = CustomEvent::new_with_event_init_dict(&event.dom_name(), &init) {
+            let _ = window.dispatch_event(custom.as_ref());
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::game_state::GamePhase;
+use crate::weapon::components::WeaponKind;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PickupKind {
+    Coin,
+    Bonus,
+    Weapon,
+}
+
+/// A page-facing event, dispatched on `window` as a `CustomEvent` named
+/// `gunday-<name>` with the variant's fields, camelCased, as its `detail`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged, rename_all_fields = "camelCase")]
+pub enum BridgeEvent {
+    Phase {
+        phase: GamePhase,
+    },
+    PlayerDamaged {
+        lives: u8,
+        knockback_dir: f32,
+    },
+    BossStage {
+        stage: u32,
+    },
+    BossDefeated {},
+    Score {
+        points: u32,
+        multiplier: u32,
+    },
+    Pickup {
+        kind: PickupKind,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        weapon: Option<WeaponKind>,
+    },
+    /// How a run ended. `code` is the signed attestation of a won run, see
+    /// `attestation`.
+    Result {
+        win: bool,
+        score: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        code: Option<String>,
+    },
+    Exit {},
+}
+
+impl BridgeEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BridgeEvent::Phase { .. } => "phase",
+            BridgeEvent::PlayerDamaged { .. } => "player-damaged",
+            BridgeEvent::BossStage { .. } => "boss-stage",
+            BridgeEvent::BossDefeated {} => "boss-defeated",
+            BridgeEvent::Score { .. } => "score",
+            BridgeEvent::Pickup { .. } => "pickup",
+            BridgeEvent::Result { .. } => "result",
+            BridgeEvent::Exit {} => "exit",
+        }
+    }
+
+    pub fn dom_name(&self) -> String {
+        format!("gunday-{}", self.name())
+    }
+}
@@ -0,0 +1,92 @@
+use super::backend::EventBridge;
+use super::event::{BridgeEvent, PickupKind};
+use crate::attestation::sign_current_run;
+use crate::boss::events::{BossDefeatedEvent, BossStageTransitionEvent};
+use crate::components::{CollectedItem, PickupCollectedEvent, QuitRequested};
+use crate::game_state::GamePhaseChanged;
+use crate::player::components::{Player, PlayerLives};
+use crate::player::player_damage_system::PlayerDamagedEvent;
+use crate::score::Score;
+use bevy::prelude::*;
+
+pub fn forward_phase_system(bridge: Res<EventBridge>, mut changed: EventReader<GamePhaseChanged>) {
+    for change in changed.read() {
+        bridge.send(BridgeEvent::Phase { phase: change.next });
+    }
+}
+
+pub fn forward_player_damage_system(
+    bridge: Res<EventBridge>,
+    mut damaged: EventReader<PlayerDamagedEvent>,
+    players: Query<&PlayerLives, With<Player>>,
+) {
+    for event in damaged.read() {
+        let lives = players.iter().next().map_or(0, |lives| lives.current);
+        bridge.send(BridgeEvent::PlayerDamaged {
+            lives,
+            knockback_dir: event.knockback_dir,
+        });
+    }
+}
+
+pub fn forward_boss_system(
+    bridge: Res<EventBridge>,
+    mut transitions: EventReader<BossStageTransitionEvent>,
+    mut defeated: EventReader<BossDefeatedEvent>,
+) {
+    // The only transition there is goes into stage two.
+    for _ in transitions.read() {
+        bridge.send(BridgeEvent::BossStage { stage: 2 });
+    }
+    for _ in defeated.read() {
+        bridge.send(BridgeEvent::BossDefeated {});
+    }
+}
+
+/// Only sends when the points change; the combo timer ticks every frame.
+pub fn forward_score_system(
+    bridge: Res<EventBridge>,
+    score: Res<Score>,
+    mut last_points: Local<Option<u32>>,
+) {
+    if *last_points == Some(score.points) {
+        return;
+    }
+    *last_points = Some(score.points);
+    bridge.send(BridgeEvent::Score {
+        points: score.points,
+        multiplier: score.multiplier(),
+    });
+}
+
+pub fn forward_pickup_system(
+    bridge: Res<EventBridge>,
+    mut collected: EventReader<PickupCollectedEvent>,
+) {
+    for event in collected.read() {
+        let (kind, weapon) = match event.item {
+            CollectedItem::Coin => (PickupKind::Coin, None),
+            CollectedItem::Bonus => (PickupKind::Bonus, None),
+            CollectedItem::Weapon(weapon) => (PickupKind::Weapon, Some(weapon)),
+        };
+        bridge.send(BridgeEvent::Pickup { kind, weapon });
+    }
+}
+
+/// Quitting from the pause menu or the results screen tells the page to take
+/// the canvas down.
+pub fn forward_quit_system(bridge: Res<EventBridge>, mut quits: EventReader<QuitRequested>) {
+    if quits.read().last().is_some() {
+        bridge.send(BridgeEvent::Exit {});
+    }
+}
+
+/// Tells the page how the run ended. Queued as a command by whatever ends
+/// the run, since a win is signed from the whole run log.
+pub fn send_run_result(world: &mut World, win: bool) {
+    let score = world.resource::<Score>().points;
+    let code = win.then(|| sign_current_run(world));
+    world
+        .resource::<EventBridge>()
+        .send(BridgeEvent::Result { win, score, code });
+}
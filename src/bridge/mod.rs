@@ -0,0 +1,7 @@
+pub mod backend;
+pub mod event;
+pub mod forward_system;
+pub mod plugin;
+
+pub use forward_system::send_run_result;
+pub use plugin::BridgePlugin;
@@ -0,0 +1,25 @@
+use super::backend::EventBridge;
+use super::forward_system::{
+    forward_boss_system, forward_phase_system, forward_pickup_system, forward_player_damage_system,
+    forward_quit_system, forward_score_system,
+};
+use bevy::prelude::*;
+
+/// Forwards gameplay events to the page. See `BridgeEvent` for the shape.
+pub struct BridgePlugin;
+
+impl Plugin for BridgePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EventBridge>().add_systems(
+            Update,
+            (
+                forward_phase_system,
+                forward_player_damage_system,
+                forward_boss_system,
+                forward_score_system,
+                forward_pickup_system,
+                forward_quit_system,
+            ),
+        );
+    }
+}
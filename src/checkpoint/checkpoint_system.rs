@@ -0,0 +1,193 @@
+use super::components::{CheckpointBanner, CheckpointRestored, CheckpointSnapshot, Checkpoints};
+use super::config::CHECKPOINT_CONFIG;
+use crate::boss::components::{Boss, BossCannon, ExplodingPart};
+use crate::components::{CameraState, MainCamera};
+use crate::constants::{SCREEN_HEIGHT, Z_FOREGROUND};
+use crate::effects::explosion_anim::Explosion;
+use crate::enemy_a::robot_components::EnemyRobot;
+use crate::enemy_b::components::{EnemyB, Grenade};
+use crate::enemy_c::components::EnemyC;
+use crate::game_state::{GamePhaseChanged, GamePhaseTransitionTimer, GameState};
+use crate::miniboss::components::{Miniboss, MinibossGrenade};
+use crate::player::components::{Player, PlayerGameOver, PlayerRespawning, PlayerSpawnPoint};
+use crate::player::setup_player::player_spawn_translation;
+use crate::player::track_player_position_system::PositionTriggerState;
+use crate::projectile::components::Projectile;
+use crate::score::Score;
+use crate::soundtrack::{SoundtrackController, Stinger, play_stinger};
+use crate::spawn::EdgeSpawnManager;
+use bevy::prelude::*;
+
+type CheckpointCamera = (With<MainCamera>, Without<Player>);
+
+/// Whatever is fighting the player when they continue gets cleared away.
+type Hostile = Or<(
+    With<EnemyRobot>,
+    With<EnemyB>,
+    With<Grenade>,
+    With<EnemyC>,
+    With<Miniboss>,
+    With<MinibossGrenade>,
+    With<Boss>,
+    With<BossCannon>,
+    With<ExplodingPart>,
+    With<Projectile>,
+    With<Explosion>,
+    With<GamePhaseTransitionTimer>,
+)>;
+
+pub fn checkpoint_reach_system(
+    mut commands: Commands,
+    mut checkpoints: ResMut<Checkpoints>,
+    game_state: Res<GameState>,
+    trigger_state: Res<PositionTriggerState>,
+    spawn_manager: Res<EdgeSpawnManager>,
+    player_query: Query<&Transform, (With<Player>, Without<PlayerGameOver>)>,
+    camera_query: Query<(Entity, &Transform, &CameraState), CheckpointCamera>,
+) {
+    if checkpoints.disabled {
+        return;
+    }
+    let Some(player_transform) = player_query.iter().next() else {
+        return;
+    };
+    let Some((camera_entity, camera_transform, camera_state)) = camera_query.iter().next() else {
+        return;
+    };
+
+    let player_x = player_transform.translation.x;
+    let Some(marker_x) = checkpoints
+        .markers
+        .iter()
+        .filter(|marker| !marker.reached && player_x >= marker.x)
+        .map(|marker| marker.x)
+        .reduce(f32::max)
+    else {
+        return;
+    };
+    for marker in checkpoints.markers.iter_mut() {
+        if marker.x <= player_x {
+            marker.reached = true;
+        }
+    }
+
+    let mut player_position = player_spawn_translation();
+    player_position.x = marker_x;
+    checkpoints.snapshot = Some(CheckpointSnapshot::capture(
+        game_state.phase(),
+        &trigger_state,
+        &spawn_manager,
+        camera_transform.translation.x,
+        camera_state,
+        player_position,
+    ));
+
+    commands.entity(camera_entity).with_children(|parent| {
+        parent.spawn((
+            Text2d::new("CHECKPOINT"),
+            TextFont::from_font_size(CHECKPOINT_CONFIG.banner_font_size),
+            TextColor(Color::WHITE),
+            Transform::from_xyz(0.0, SCREEN_HEIGHT * 0.25, Z_FOREGROUND + 10.0),
+            CheckpointBanner {
+                timer: CHECKPOINT_CONFIG.banner_duration,
+            },
+        ));
+    });
+    play_stinger(&mut commands, Stinger::CheckpointReached);
+}
+
+pub fn checkpoint_banner_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut banners: Query<(Entity, &mut CheckpointBanner, &mut TextColor)>,
+) {
+    for (entity, mut banner, mut color) in banners.iter_mut() {
+        banner.timer -= time.delta_secs();
+        if banner.timer <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let fraction = (banner.timer / CHECKPOINT_CONFIG.banner_duration).min(1.0);
+        color.0 = color.0.with_alpha(fraction);
+    }
+}
+
+/// Puts the level's progress, music and phase back to the snapshot.
+pub fn restore_checkpoint_state_system(
+    mut restored: EventReader<CheckpointRestored>,
+    checkpoints: Res<Checkpoints>,
+    mut game_state: ResMut<GameState>,
+    mut trigger_state: ResMut<PositionTriggerState>,
+    mut spawn_manager: ResMut<EdgeSpawnManager>,
+    mut controller: ResMut<SoundtrackController>,
+    mut phase_changed: EventWriter<GamePhaseChanged>,
+) {
+    if restored.read().last().is_none() {
+        return;
+    }
+    let Some(snapshot) = checkpoints.snapshot.as_ref() else {
+        return;
+    };
+
+    game_state.transition_to(snapshot.phase);
+    // Spawners that start on a phase change (miniboss, boss) get a second go.
+    phase_changed.write(GamePhaseChanged {
+        next: snapshot.phase,
+    });
+
+    trigger_state.triggers = snapshot.triggers.clone();
+    trigger_state.camera_locked = snapshot.camera_locked;
+    for (definition, spawned) in spawn_manager
+        .definitions
+        .iter_mut()
+        .zip(snapshot.edge_spawned.iter())
+    {
+        definition.spawned = *spawned;
+    }
+
+    if let Some(track_set) = snapshot.track_set() {
+        controller.request_track_set(track_set);
+    }
+}
+
+/// Clears out enemies and moves the camera and the player back to the
+/// checkpoint with full lives.
+pub fn restore_checkpoint_actors_system(
+    mut commands: Commands,
+    mut restored: EventReader<CheckpointRestored>,
+    checkpoints: Res<Checkpoints>,
+    hostiles: Query<Entity, Hostile>,
+    player_query: Query<Entity, With<Player>>,
+    mut camera_query: Query<(&mut Transform, &mut CameraState), CheckpointCamera>,
+    mut score: ResMut<Score>,
+) {
+    if restored.read().last().is_none() {
+        return;
+    }
+    let Some(snapshot) = checkpoints.snapshot.as_ref() else {
+        return;
+    };
+
+    for entity in hostiles.iter() {
+        commands.entity(entity).try_despawn();
+    }
+
+    for (mut transform, mut camera_state) in camera_query.iter_mut() {
+        transform.translation.x = snapshot.camera_x;
+        *camera_state = CameraState {
+            current_x: snapshot.camera_x,
+            max_reached_x: snapshot.camera_max_reached_x,
+            lock_position: snapshot.camera_lock_position,
+        };
+    }
+
+    // The respawn system restores the lives and the sprite.
+    for entity in player_query.iter() {
+        commands.entity(entity).remove::<PlayerGameOver>().insert((
+            PlayerSpawnPoint(snapshot.player_position),
+            PlayerRespawning { timer: 0.0 },
+        ));
+    }
+
+    score.break_combo();
+}
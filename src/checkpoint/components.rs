@@ -0,0 +1,96 @@
+use crate::components::CameraState;
+use crate::game_state::GamePhase;
+use crate::player::track_player_position_system::{PositionTrigger, PositionTriggerState};
+use crate::soundtrack::TrackSetName;
+use crate::spawn::EdgeSpawnManager;
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CheckpointMarker {
+    pub x: f32,
+    pub reached: bool,
+}
+
+/// Everything needed to put the level back the way it was when the player
+/// passed a checkpoint.
+#[derive(Clone, Debug)]
+pub struct CheckpointSnapshot {
+    pub(crate) phase: GamePhase,
+    pub(crate) triggers: Vec<PositionTrigger>,
+    pub(crate) camera_locked: bool,
+    pub(crate) edge_spawned: Vec<bool>,
+    pub(crate) camera_x: f32,
+    pub(crate) camera_max_reached_x: f32,
+    pub(crate) camera_lock_position: Option<f32>,
+    pub(crate) player_position: Vec3,
+}
+
+impl CheckpointSnapshot {
+    pub(crate) fn capture(
+        phase: GamePhase,
+        trigger_state: &PositionTriggerState,
+        spawn_manager: &EdgeSpawnManager,
+        camera_x: f32,
+        camera_state: &CameraState,
+        player_position: Vec3,
+    ) -> Self {
+        Self {
+            phase,
+            triggers: trigger_state.triggers.clone(),
+            camera_locked: trigger_state.camera_locked,
+            edge_spawned: spawn_manager
+                .definitions
+                .iter()
+                .map(|definition| definition.spawned)
+                .collect(),
+            camera_x,
+            camera_max_reached_x: camera_state.max_reached_x,
+            camera_lock_position: camera_state.lock_position,
+            player_position,
+        }
+    }
+
+    /// Track set of the last music trigger fired before the checkpoint.
+    pub(crate) fn track_set(&self) -> Option<TrackSetName> {
+        self.triggers
+            .iter()
+            .rev()
+            .filter(|trigger| trigger.fired)
+            .find_map(|trigger| trigger.track_set.clone())
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Checkpoints {
+    pub markers: Vec<CheckpointMarker>,
+    pub snapshot: Option<CheckpointSnapshot>,
+    /// Set for game modes without continues; nothing gets captured.
+    pub disabled: bool,
+}
+
+impl Checkpoints {
+    pub fn reset(&mut self, markers: impl IntoIterator<Item = f32>) {
+        self.markers = markers
+            .into_iter()
+            .map(|x| CheckpointMarker { x, reached: false })
+            .collect();
+        self.snapshot = None;
+    }
+}
+
+/// Sent when the player picks "continue" on the game over screen.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CheckpointRestored;
+
+#[derive(Component)]
+pub struct CheckpointBanner {
+    pub timer: f32,
+}
+
+#[derive(Component)]
+pub struct ContinuePrompt {
+    pub timer: f32,
+}
+
+#[derive(Component)]
+pub struct ContinueCountdownText;
@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, Copy)]
+pub struct CheckpointConfig {
+    /// Seconds the continue prompt waits before giving up on its own.
+    pub continue_timeout: f32,
+    pub banner_duration: f32,
+    pub banner_font_size: f32,
+    pub prompt_font_size: f32,
+    pub prompt_line_spacing: f32,
+}
+
+pub const CHECKPOINT_CONFIG: CheckpointConfig = CheckpointConfig {
+    continue_timeout: 10.0,
+    banner_duration: 1.5,
+    banner_font_size: 28.0,
+    prompt_font_size: 24.0,
+    prompt_line_spacing: 36.0,
+};
@@ -0,0 +1,132 @@
+use super::components::{CheckpointRestored, Checkpoints, ContinueCountdownText, ContinuePrompt};
+use super::config::CHECKPOINT_CONFIG;
+use crate::components::MainCamera;
+use crate::constants::Z_FOREGROUND;
+use crate::player::components::{Player, PlayerActions, PlayerGameOver};
+use crate::player::input_map::{InputAction, InputMap};
+use crate::systems::PlayerControl;
+use bevy::prelude::*;
+
+type AwaitingContinue = (With<Player>, Without<ContinuePrompt>);
+
+#[derive(Component)]
+pub struct ContinuePromptUi;
+
+/// Once the game over fall has played out, offers to continue if a
+/// checkpoint was reached.
+pub fn continue_offer_system(
+    mut commands: Commands,
+    checkpoints: Res<Checkpoints>,
+    mut control: ResMut<PlayerControl>,
+    players: Query<(Entity, &PlayerGameOver), AwaitingContinue>,
+) {
+    if checkpoints.snapshot.is_none() {
+        return;
+    }
+    for (entity, game_over) in players.iter() {
+        if game_over.timer > 0.0 || game_over.continue_declined || game_over.result_sent {
+            continue;
+        }
+        commands.entity(entity).insert(ContinuePrompt {
+            timer: CHECKPOINT_CONFIG.continue_timeout,
+        });
+        // Input is read through the normal action path so replays see the choice.
+        control.enabled = true;
+    }
+}
+
+pub fn continue_prompt_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    actions: Res<PlayerActions>,
+    mut control: ResMut<PlayerControl>,
+    mut players: Query<(Entity, &mut PlayerGameOver, &mut ContinuePrompt)>,
+    mut restored: EventWriter<CheckpointRestored>,
+) {
+    for (entity, mut game_over, mut prompt) in players.iter_mut() {
+        if actions.jump.just_pressed {
+            commands.entity(entity).remove::<ContinuePrompt>();
+            restored.write(CheckpointRestored);
+            continue;
+        }
+
+        prompt.timer -= time.delta_secs();
+        if actions.shoot.just_pressed || prompt.timer <= 0.0 {
+            commands.entity(entity).remove::<ContinuePrompt>();
+            game_over.continue_declined = true;
+            control.enabled = false;
+        }
+    }
+}
+
+pub fn continue_prompt_ui_system(
+    mut commands: Commands,
+    input_map: Res<InputMap>,
+    prompts: Query<&ContinuePrompt>,
+    camera_query: Query<Entity, With<MainCamera>>,
+    ui_roots: Query<Entity, With<ContinuePromptUi>>,
+    mut countdown_text: Query<&mut Text2d, With<ContinueCountdownText>>,
+) {
+    let Some(prompt) = prompts.iter().next() else {
+        for entity in ui_roots.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    let seconds_left = prompt.timer.max(0.0).ceil() as u32;
+    if ui_roots.iter().next().is_some() {
+        for mut text in countdown_text.iter_mut() {
+            text.0 = seconds_left.to_string();
+        }
+        return;
+    }
+
+    let Some(camera_entity) = camera_query.iter().next() else {
+        return;
+    };
+    let key_label = |action| {
+        input_map
+            .bindings(action)
+            .first()
+            .map(|binding| binding.label())
+            .unwrap_or_else(|| action.label().to_owned())
+    };
+    let hint = format!(
+        "{}: continue    {}: give up",
+        key_label(InputAction::Jump),
+        key_label(InputAction::Shoot)
+    );
+    let spacing = CHECKPOINT_CONFIG.prompt_line_spacing;
+    let font = TextFont::from_font_size(CHECKPOINT_CONFIG.prompt_font_size);
+
+    commands.entity(camera_entity).with_children(|parent| {
+        parent
+            .spawn((
+                ContinuePromptUi,
+                Transform::from_xyz(0.0, 0.0, Z_FOREGROUND + 20.0),
+                Visibility::Visible,
+            ))
+            .with_children(|root| {
+                root.spawn((
+                    Text2d::new("CONTINUE?"),
+                    font.clone(),
+                    TextColor(Color::WHITE),
+                    Transform::from_xyz(0.0, spacing, 0.0),
+                ));
+                root.spawn((
+                    Text2d::new(hint),
+                    font.clone(),
+                    TextColor(Color::WHITE),
+                    Transform::default(),
+                ));
+                root.spawn((
+                    Text2d::new(seconds_left.to_string()),
+                    font,
+                    TextColor(Color::srgb(1.0, 0.85, 0.3)),
+                    Transform::from_xyz(0.0, -spacing, 0.0),
+                    ContinueCountdownText,
+                ));
+            });
+    });
+}
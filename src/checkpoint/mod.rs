@@ -0,0 +1,15 @@
+pub mod checkpoint_system;
+pub mod components;
+pub mod config;
+pub mod continue_system;
+pub mod plugin;
+
+pub use checkpoint_system::{
+    checkpoint_banner_system, checkpoint_reach_system, restore_checkpoint_actors_system,
+    restore_checkpoint_state_system,
+};
+pub use components::{CheckpointRestored, Checkpoints};
+pub use continue_system::{
+    continue_offer_system, continue_prompt_system, continue_prompt_ui_system,
+};
+pub use plugin::CheckpointPlugin;
@@ -0,0 +1,37 @@
+use super::*;
+use crate::boss::spawn_system::boss_spawn_system;
+use crate::miniboss::spawn_miniboss_on_phase_start;
+use crate::player::{player_game_over_system, track_player_position_system};
+use bevy::prelude::*;
+
+pub struct CheckpointPlugin;
+
+impl Plugin for CheckpointPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Checkpoints>()
+            .add_event::<CheckpointRestored>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    checkpoint_reach_system.after(track_player_position_system),
+                    // The phase spawners run first (the miniboss one is already
+                    // ahead through contact damage) and pick a restored phase up
+                    // on the next tick.
+                    (
+                        continue_offer_system,
+                        continue_prompt_system,
+                        restore_checkpoint_state_system,
+                        restore_checkpoint_actors_system,
+                    )
+                        .chain()
+                        .after(player_game_over_system)
+                        .after(spawn_miniboss_on_phase_start)
+                        .after(boss_spawn_system),
+                ),
+            )
+            .add_systems(
+                Update,
+                (checkpoint_banner_system, continue_prompt_ui_system),
+            );
+    }
+}
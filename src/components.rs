@@ -1,7 +1,10 @@
-use crate::constants::{GROUND_RECT_HEIGHT, WORLD_WIDTH};
+use crate::constants::WORLD_WIDTH;
+use crate::interpolation::TransformInterpolation;
+use crate::weapon::components::WeaponKind;
 use bevy::prelude::*;
 
 #[derive(Component)]
+#[require(TransformInterpolation)]
 pub struct MainCamera;
 
 #[derive(Component)]
@@ -41,25 +44,83 @@ impl LayerGeometry {
     }
 }
 
-#[derive(Resource)]
+#[derive(Resource, Default)]
 pub struct LayerGeometryStorage {
     pub objects: Vec<LayerGeometry>,
 }
 
-impl Default for LayerGeometryStorage {
+/// Hit points of anything the player can shoot.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Health {
+    pub current: u32,
+    pub max: u32,
+}
+
+impl Health {
+    pub const fn new(max: u32) -> Self {
+        Self { current: max, max }
+    }
+
+    /// Returns true when this hit used up the last of the health.
+    pub fn apply(&mut self, damage: Damage) -> bool {
+        let was_alive = !self.is_depleted();
+        self.current = self.current.saturating_sub(damage.0);
+        was_alive && self.is_depleted()
+    }
+
+    pub fn is_depleted(&self) -> bool {
+        self.current == 0
+    }
+}
+
+/// Hit points a projectile takes off whatever it hits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Damage(pub u32);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnemyKind {
+    Robot,
+    EnemyB,
+    EnemyC,
+    Miniboss,
+}
+
+/// Sent when an enemy is killed by the player, at the enemy's position.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct EnemyDefeatedEvent {
+    pub kind: EnemyKind,
+    pub position: Vec3,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CollectedItem {
+    Coin,
+    Bonus,
+    Weapon(WeaponKind),
+}
+
+/// Sent when the player picks up a coin, bonus or weapon.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PickupCollectedEvent {
+    pub item: CollectedItem,
+}
+
+/// Sent by the menus to leave the game. Native builds exit, the page gets
+/// `gunday-exit` and decides what to do with the canvas.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct QuitRequested;
+
+/// Despawned when the campaign moves on to the next level.
+#[derive(Component)]
+pub struct LevelEntity;
+
+#[derive(Resource, Clone, Copy)]
+pub struct WorldBounds {
+    pub width: f32,
+}
+
+impl Default for WorldBounds {
     fn default() -> Self {
-        Self {
-            objects: vec![
-                LayerGeometry::new_rectangle(0.0, 0.0, WORLD_WIDTH, GROUND_RECT_HEIGHT),
-                LayerGeometry::new_rectangle(892.0, 232.0, 26.0, 438.0),
-                LayerGeometry::new_rectangle(3408.0, 338.0, 158.0, 18.0),
-                LayerGeometry::new_rectangle(3924.0, 338.0, 158.0, 18.0),
-                LayerGeometry::new_rectangle(4389.0, 338.0, 158.0, 18.0),
-                LayerGeometry::new_rectangle(6873.0, 374.0, 18.0, 298.0),
-                LayerGeometry::new_rectangle(7895.0, 374.0, 18.0, 298.0),
-                LayerGeometry::new_rectangle(8008.0, 374.0, 18.0, 298.0),
-                LayerGeometry::new_rectangle(10242.0, 338.0, 117.0, 18.0),
-            ],
-        }
+        Self { width: WORLD_WIDTH }
     }
 }
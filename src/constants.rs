@@ -4,6 +4,8 @@ pub const WORLD_WIDTH: f32 = 11648.0;
 
 pub const CAMERA_OFFSET: f32 = SCREEN_WIDTH / 4.0;
 
+pub const SIMULATION_HZ: f64 = 60.0;
+
 pub const DEFAULT_GRAVITY: f32 = -1400.0;
 
 pub const GROUND_RECT_HEIGHT: f32 = 118.0;
@@ -0,0 +1,56 @@
+use crate::components::Health;
+use bevy::prelude::*;
+use serde::Deserialize;
+use serde::de::IntoDeserializer;
+use serde::de::value::{Error, StrDeserializer};
+
+/// How tough enemies are. Only applies to enemies spawned after a change.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Parses the lowercase name through the derived `Deserialize`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name: StrDeserializer<'_, Error> = name.into_deserializer();
+        Difficulty::deserialize(name).ok()
+    }
+
+    fn health_scale(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.5,
+        }
+    }
+}
+
+pub struct DifficultyPlugin;
+
+impl Plugin for DifficultyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Difficulty>()
+            .add_systems(FixedUpdate, scale_enemy_health_system);
+    }
+}
+
+/// Only enemies carry `Health`; the player counts lives instead.
+fn scale_enemy_health_system(
+    difficulty: Res<Difficulty>,
+    mut spawned: Query<&mut Health, Added<Health>>,
+) {
+    if *difficulty == Difficulty::Normal {
+        return;
+    }
+
+    let scale = difficulty.health_scale();
+    for mut health in spawned.iter_mut() {
+        let max = ((health.max as f32 * scale).round() as u32).max(1);
+        *health = Health::new(max);
+    }
+}
@@ -1 +1,2 @@
 pub mod explosion_anim;
+pub mod screen_pulse;
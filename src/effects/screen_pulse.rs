@@ -0,0 +1,55 @@
+use crate::soundtrack::MusicBeat;
+use bevy::prelude::*;
+
+/// Full-screen overlay that flashes faintly on every bar of the music.
+#[derive(Component)]
+pub struct ScreenPulse {
+    pub strength: f32,
+}
+
+const PULSE_STRENGTH: f32 = 0.05;
+/// The first bar of each loop flashes harder.
+const LOOP_PULSE_STRENGTH: f32 = 0.09;
+const PULSE_FADE_SECONDS: f32 = 0.25;
+
+pub fn spawn_screen_pulse(mut commands: Commands) {
+    commands.spawn((
+        ScreenPulse { strength: 0.0 },
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        GlobalZIndex(1),
+    ));
+}
+
+/// Beats keep coming while the game is paused; they are read and dropped
+/// so none flash when it resumes.
+pub fn screen_pulse_system(
+    time: Res<Time<Real>>,
+    game_time: Res<Time<Virtual>>,
+    mut beats: EventReader<MusicBeat>,
+    mut q: Query<(&mut ScreenPulse, &mut BackgroundColor)>,
+) {
+    let paused = game_time.is_paused();
+    let peak = beats
+        .read()
+        .filter(|beat| !paused && beat.is_downbeat())
+        .map(|beat| {
+            if beat.bar == 0 {
+                LOOP_PULSE_STRENGTH
+            } else {
+                PULSE_STRENGTH
+            }
+        })
+        .fold(0.0, f32::max);
+
+    for (mut pulse, mut color) in q.iter_mut() {
+        let faded = pulse.strength - time.delta_secs() * PULSE_STRENGTH / PULSE_FADE_SECONDS;
+        pulse.strength = faded.max(peak).max(0.0);
+        color.0 = Color::srgba(1.0, 1.0, 1.0, pulse.strength);
+    }
+}
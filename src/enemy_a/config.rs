@@ -14,6 +14,7 @@ pub struct EnemyAConfig {
     pub death_total_move: f32,
     pub projectile_spawn_offset: Vec3,
     pub spawn_ground_offset: f32,
+    pub hit_points: u32,
 }
 
 pub const ENEMY_A_CONFIG: EnemyAConfig = EnemyAConfig {
@@ -29,4 +30,5 @@ pub const ENEMY_A_CONFIG: EnemyAConfig = EnemyAConfig {
     death_total_move: 5.0,
     projectile_spawn_offset: Vec3::new(0.0, 16.0, 0.0),
     spawn_ground_offset: 5.0,
+    hit_points: 1,
 };
@@ -1,5 +1,6 @@
 pub mod config;
 pub mod infected;
+pub mod plugin;
 pub mod robot_animation_system;
 pub mod robot_behavior_system;
 pub mod robot_components;
@@ -11,6 +12,7 @@ pub mod robot_spawn_protection_system;
 
 pub use config::ENEMY_A_CONFIG;
 pub use infected::{InfectedEnemyConfig, InfectedEnemyRobot, infected_transform_system};
+pub use plugin::EnemyAPlugin;
 pub use robot_animation_system::enemy_robot_animation_system;
 pub use robot_behavior_system::enemy_robot_behavior_system;
 pub use robot_death_anim_system::enemy_robot_death_anim_system;
@@ -0,0 +1,30 @@
+use super::*;
+use crate::projectile::projectile_movement_system;
+use crate::spawn::edge_spawn_system;
+use bevy::prelude::*;
+
+pub struct EnemyAPlugin;
+
+impl Plugin for EnemyAPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InfectedEnemyConfig>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    enemy_spawn_protection_system.after(edge_spawn_system),
+                    enemy_robot_behavior_system.after(edge_spawn_system),
+                    enemy_robot_animation_system.after(enemy_robot_behavior_system),
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    enemy_robot_projectile_system.after(enemy_robot_behavior_system),
+                    enemy_robot_hit_system.after(projectile_movement_system),
+                    infected_transform_system.after(enemy_robot_hit_system),
+                    enemy_robot_death_anim_system.after(enemy_robot_hit_system),
+                    enemy_robot_despawn_system.after(enemy_robot_death_anim_system),
+                ),
+            );
+    }
+}
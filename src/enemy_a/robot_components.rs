@@ -1,7 +1,10 @@
 use super::config::ENEMY_A_CONFIG;
+use crate::components::Health;
+use crate::interpolation::TransformInterpolation;
 use bevy::prelude::*;
 
 #[derive(Component)]
+#[require(TransformInterpolation)]
 pub struct EnemyRobot;
 
 #[derive(Component)]
@@ -109,6 +112,7 @@ pub struct EnemyRobotBundle {
     pub state: EnemyRobotState,
     pub run_anim: EnemyRunAnim,
     pub shoot_timer: EnemyShootTimer,
+    pub health: Health,
 }
 
 impl EnemyRobotBundle {
@@ -128,6 +132,7 @@ impl EnemyRobotBundle {
             },
             run_anim: EnemyRunAnim::default(),
             shoot_timer: EnemyShootTimer::default(),
+            health: Health::new(ENEMY_A_CONFIG.hit_points),
         }
     }
 }
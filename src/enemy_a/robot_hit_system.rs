@@ -3,7 +3,7 @@ use super::robot_components::*;
 use super::robot_components::{ENEMY_ROBOT_HEIGHT, ENEMY_ROBOT_WIDTH};
 use crate::assets::GameAssets;
 use crate::audio::{SfxEmitters, play_sfx_once};
-use crate::constants::PROJECTILE_SIZE;
+use crate::components::{Damage, EnemyDefeatedEvent, EnemyKind, Health};
 use crate::player::components::Player;
 use crate::projectile::components::{
     PlayerProjectile, Projectile, ProjectileHitEvent, swept_projectile_hit_center,
@@ -18,6 +18,7 @@ pub fn enemy_robot_hit_system(
             Entity,
             &Transform,
             &mut EnemyRobotState,
+            &mut Health,
             Option<&EnemySpawnProtection>,
             Option<&InfectedEnemyRobot>,
         ),
@@ -33,13 +34,15 @@ pub fn enemy_robot_hit_system(
     assets: Res<GameAssets>,
     emitters: Res<SfxEmitters>,
 ) {
-    let projectile_data: Vec<(Entity, Vec2, Vec2)> = projectiles
+    let projectile_data: Vec<(Entity, Vec2, Vec2, Vec2, Damage)> = projectiles
         .iter()
         .map(|(entity, transform, projectile)| {
             (
                 entity,
                 projectile.previous_translation,
                 Vec2::new(transform.translation.x, transform.translation.y),
+                projectile.size,
+                projectile.damage,
             )
         })
         .collect();
@@ -51,7 +54,9 @@ pub fn enemy_robot_hit_system(
     let assets = assets.as_ref();
     let emitters = emitters.as_ref();
 
-    for (enemy_entity, enemy_transform, mut state, protection, infected) in enemies.iter_mut() {
+    for (enemy_entity, enemy_transform, mut state, mut health, protection, infected) in
+        enemies.iter_mut()
+    {
         if consumed.len() == projectile_data.len() {
             break;
         }
@@ -68,21 +73,30 @@ pub fn enemy_robot_hit_system(
             enemy_pos.y - ENEMY_ROBOT_HEIGHT / 2.0,
         );
         let enemy_rect_size = Vec2::new(ENEMY_ROBOT_WIDTH, ENEMY_ROBOT_HEIGHT);
-        for (proj_entity, start_center, end_center) in projectile_data.iter() {
+        for (proj_entity, start_center, end_center, size, damage) in projectile_data.iter() {
             if consumed.contains(proj_entity) {
                 continue;
             }
             if let Some(hit_center) = swept_projectile_hit_center(
                 *start_center,
                 *end_center,
-                Vec2::splat(PROJECTILE_SIZE),
+                *size,
                 enemy_rect_min,
                 enemy_rect_size,
             ) {
-                state.state = EnemyRobotStateKind::Hit;
                 let hit_pos = Vec3::new(hit_center.x, hit_center.y, enemy_transform.translation.z);
                 hit_writer.write(ProjectileHitEvent { position: hit_pos });
                 commands.entity(*proj_entity).despawn();
+                consumed.insert(*proj_entity);
+                if !health.apply(*damage) {
+                    play_sfx_once(
+                        &mut commands,
+                        emitters.enemy_hit,
+                        assets.enemy_hit_sfx.clone(),
+                    );
+                    continue;
+                }
+                state.state = EnemyRobotStateKind::Hit;
                 if infected.is_some() {
                     let baseline = enemy_transform.translation.y - ENEMY_ROBOT_HEIGHT * 0.5;
                     commands
@@ -112,6 +126,10 @@ pub fn enemy_robot_hit_system(
                     } else {
                         1.0
                     };
+                    commands.send_event(EnemyDefeatedEvent {
+                        kind: EnemyKind::Robot,
+                        position: enemy_transform.translation,
+                    });
                     commands
                         .entity(enemy_entity)
                         .insert(EnemyDespawnTimer::default());
@@ -124,7 +142,6 @@ pub fn enemy_robot_hit_system(
                         assets.enemy_death_sfx.clone(),
                     );
                 }
-                consumed.insert(*proj_entity);
                 break;
             }
         }
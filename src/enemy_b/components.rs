@@ -1,7 +1,10 @@
 use super::config::ENEMY_B_CONFIG;
+use crate::components::Health;
+use crate::interpolation::TransformInterpolation;
 use bevy::prelude::*;
 
 #[derive(Component)]
+#[require(TransformInterpolation)]
 pub struct EnemyB;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -110,6 +113,7 @@ pub struct EnemyBBundle {
     pub state: EnemyBState,
     pub anim: EnemyBThrowAnim,
     pub throw_timer: EnemyBThrowTimer,
+    pub health: Health,
 }
 
 impl EnemyBBundle {
@@ -128,11 +132,13 @@ impl EnemyBBundle {
             },
             anim: EnemyBThrowAnim::new(),
             throw_timer: EnemyBThrowTimer::default(),
+            health: Health::new(ENEMY_B_CONFIG.hit_points),
         }
     }
 }
 
 #[derive(Component)]
+#[require(TransformInterpolation)]
 pub struct Grenade {
     pub velocity: Vec2,
     pub rotation_timer: f32,
@@ -14,6 +14,7 @@ pub struct EnemyBConfig {
     pub grenade_rotation_fps: f32,
     pub grenade_rotation_step: f32,
     pub explosion_frame_time: f32,
+    pub hit_points: u32,
 }
 
 pub const ENEMY_B_CONFIG: EnemyBConfig = EnemyBConfig {
@@ -29,4 +30,5 @@ pub const ENEMY_B_CONFIG: EnemyBConfig = EnemyBConfig {
     grenade_rotation_fps: 8.0,
     grenade_rotation_step: std::f32::consts::FRAC_PI_4,
     explosion_frame_time: 0.06,
+    hit_points: 1,
 };
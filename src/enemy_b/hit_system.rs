@@ -1,23 +1,17 @@
 use super::components::*;
 use crate::assets::GameAssets;
 use crate::audio::{SfxEmitters, play_sfx_once};
-use crate::constants::PROJECTILE_SIZE;
+use crate::components::{EnemyDefeatedEvent, EnemyKind, Health};
 use crate::player::components::Player;
 use crate::projectile::components::{PlayerProjectile, Projectile, swept_projectile_hit_center};
 use bevy::prelude::*;
 use std::collections::HashSet;
 
+type HittableEnemyB = (With<EnemyB>, Without<EnemyBSpawnProtection>);
+
 pub fn enemy_b_hit_system(
     mut commands: Commands,
-    mut q: Query<
-        (
-            Entity,
-            &Transform,
-            &mut EnemyBState,
-            Option<&EnemyBSpawnProtection>,
-        ),
-        With<EnemyB>,
-    >,
+    mut q: Query<(Entity, &Transform, &mut EnemyBState, &mut Health), HittableEnemyB>,
     projectiles: Query<
         (Entity, &Transform, &Projectile),
         (With<Projectile>, With<PlayerProjectile>),
@@ -30,13 +24,10 @@ pub fn enemy_b_hit_system(
     let emitters = emitters.as_ref();
     let mut consumed: HashSet<Entity> = HashSet::new();
 
-    for (enemy_e, enemy_tf, mut st, protection) in q.iter_mut() {
+    for (enemy_e, enemy_tf, mut st, mut health) in q.iter_mut() {
         if st.state == EnemyBStateKind::Hit {
             continue;
         }
-        if protection.is_some() {
-            continue;
-        }
         let enemy_min = Vec2::new(
             enemy_tf.translation.x - ENEMY_B_WIDTH / 2.0,
             enemy_tf.translation.y - ENEMY_B_HEIGHT / 2.0,
@@ -49,13 +40,21 @@ pub fn enemy_b_hit_system(
             if let Some(_hit_center) = swept_projectile_hit_center(
                 projectile.previous_translation,
                 Vec2::new(proj_tf.translation.x, proj_tf.translation.y),
-                Vec2::splat(PROJECTILE_SIZE),
+                projectile.size,
                 enemy_min,
                 enemy_size,
             ) {
-                st.state = EnemyBStateKind::Hit;
                 commands.entity(proj_e).despawn();
                 consumed.insert(proj_e);
+                if !health.apply(projectile.damage) {
+                    play_sfx_once(
+                        &mut commands,
+                        emitters.enemy_hit,
+                        assets.enemy_hit_sfx.clone(),
+                    );
+                    continue;
+                }
+                st.state = EnemyBStateKind::Hit;
                 let dir = if let Ok(ptf) = player_q.single() {
                     if ptf.translation.x < enemy_tf.translation.x {
                         1.0
@@ -65,6 +64,10 @@ pub fn enemy_b_hit_system(
                 } else {
                     1.0
                 };
+                commands.send_event(EnemyDefeatedEvent {
+                    kind: EnemyKind::EnemyB,
+                    position: enemy_tf.translation,
+                });
                 commands.entity(enemy_e).insert(EnemyBDespawnTimer::new());
                 commands.entity(enemy_e).insert(EnemyBDeathBlink::new(dir));
                 play_sfx_once(
@@ -6,6 +6,7 @@ pub mod death_anim_system;
 pub mod explosion_system;
 pub mod grenade_system;
 pub mod hit_system;
+pub mod plugin;
 
 pub use animation_system::enemy_b_animation_system;
 pub use behavior_system::enemy_b_behavior_system;
@@ -13,3 +14,4 @@ pub use death_anim_system::enemy_b_death_anim_system;
 pub use explosion_system::enemy_b_explosion_anim_system;
 pub use grenade_system::{enemy_b_grenade_collision_system, enemy_b_grenade_movement_system};
 pub use hit_system::enemy_b_hit_system;
+pub use plugin::EnemyBPlugin;
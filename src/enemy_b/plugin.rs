@@ -0,0 +1,28 @@
+use super::grenade_system::enemy_b_grenade_physics_system;
+use super::hit_system::{enemy_b_despawn_system, enemy_b_spawn_protection_system};
+use super::*;
+use crate::projectile::projectile_movement_system;
+use crate::spawn::edge_spawn_system;
+use bevy::prelude::*;
+
+pub struct EnemyBPlugin;
+
+impl Plugin for EnemyBPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (
+                enemy_b_behavior_system.after(edge_spawn_system),
+                enemy_b_animation_system.after(enemy_b_behavior_system),
+                enemy_b_grenade_physics_system,
+                enemy_b_grenade_movement_system.after(enemy_b_behavior_system),
+                enemy_b_grenade_collision_system.after(enemy_b_grenade_physics_system),
+                enemy_b_hit_system.after(projectile_movement_system),
+                enemy_b_death_anim_system.after(enemy_b_hit_system),
+                enemy_b_despawn_system.after(enemy_b_death_anim_system),
+                enemy_b_spawn_protection_system.after(edge_spawn_system),
+                enemy_b_explosion_anim_system,
+            ),
+        );
+    }
+}
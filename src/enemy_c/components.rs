@@ -1,8 +1,11 @@
 use super::config::ENEMY_C_CONFIG;
+use crate::components::Health;
+use crate::interpolation::TransformInterpolation;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
 
 #[derive(Component)]
+#[require(TransformInterpolation)]
 pub struct EnemyC;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -30,24 +33,6 @@ impl Default for EnemyCState {
     }
 }
 
-#[derive(Component)]
-pub struct EnemyCHitPoints {
-    pub current: u8,
-}
-
-impl EnemyCHitPoints {
-    pub const fn new(max: u8) -> Self {
-        Self { current: max }
-    }
-
-    pub fn take_hit(&mut self) -> bool {
-        if self.current > 0 {
-            self.current -= 1;
-        }
-        self.current == 0
-    }
-}
-
 #[derive(Component, Default)]
 pub struct EnemyCVelocity {
     pub velocity: Vec2,
@@ -151,7 +136,7 @@ pub struct EnemyCBundle {
     pub view_visibility: ViewVisibility,
     pub enemy: EnemyC,
     pub state: EnemyCState,
-    pub hp: EnemyCHitPoints,
+    pub health: Health,
     pub velocity: EnemyCVelocity,
     pub animation: EnemyCAnimation,
     pub jump: EnemyCJumpController,
@@ -174,7 +159,7 @@ impl EnemyCBundle {
                 time_in_state: 0.0,
                 facing_right,
             },
-            hp: EnemyCHitPoints::new(ENEMY_C_CONFIG.hit_points),
+            health: Health::new(ENEMY_C_CONFIG.hit_points),
             velocity: EnemyCVelocity::default(),
             animation: EnemyCAnimation::default(),
             jump: EnemyCJumpController {
@@ -1,6 +1,6 @@
 #[derive(Debug, Clone, Copy)]
 pub struct EnemyCConfig {
-    pub hit_points: u8,
+    pub hit_points: u32,
     pub run_speed: f32,
     pub jump_horizontal_speed: f32,
     pub jump_vertical_speed: f32,
@@ -1,11 +1,11 @@
 use super::components::{
     ENEMY_C_HEIGHT, ENEMY_C_WIDTH, EnemyC, EnemyCDeathBlink, EnemyCDespawnTimer, EnemyCHitFlash,
-    EnemyCHitPoints, EnemyCState, EnemyCStateKind, EnemyCVelocity,
+    EnemyCState, EnemyCStateKind, EnemyCVelocity,
 };
 use super::config::ENEMY_C_CONFIG;
 use crate::assets::GameAssets;
 use crate::audio::{SfxEmitters, play_sfx_once};
-use crate::constants::PROJECTILE_SIZE;
+use crate::components::{Damage, EnemyDefeatedEvent, EnemyKind, Health};
 use crate::effects::explosion_anim::spawn_explosion_b;
 use crate::player::components::Player;
 use crate::projectile::components::{
@@ -21,7 +21,7 @@ pub fn enemy_c_hit_system(
             Entity,
             &Transform,
             &mut EnemyCState,
-            &mut EnemyCHitPoints,
+            &mut Health,
             &mut EnemyCVelocity,
         ),
         With<EnemyC>,
@@ -35,13 +35,15 @@ pub fn enemy_c_hit_system(
     game_assets: Res<GameAssets>,
     emitters: Res<SfxEmitters>,
 ) {
-    let projectile_data: Vec<(Entity, Vec2, Vec2)> = projectiles
+    let projectile_data: Vec<(Entity, Vec2, Vec2, Vec2, Damage)> = projectiles
         .iter()
         .map(|(entity, transform, projectile)| {
             (
                 entity,
                 projectile.previous_translation,
                 Vec2::new(transform.translation.x, transform.translation.y),
+                projectile.size,
+                projectile.damage,
             )
         })
         .collect();
@@ -54,7 +56,7 @@ pub fn enemy_c_hit_system(
 
     let player_x = player_q.iter().next().map(|tf| tf.translation.x);
 
-    for (enemy_entity, transform, mut state, mut hp, mut velocity) in enemies.iter_mut() {
+    for (enemy_entity, transform, mut state, mut health, mut velocity) in enemies.iter_mut() {
         if consumed.len() == projectile_data.len() {
             break;
         }
@@ -69,7 +71,7 @@ pub fn enemy_c_hit_system(
         );
         let enemy_size = Vec2::new(ENEMY_C_WIDTH, ENEMY_C_HEIGHT);
 
-        for (proj_entity, start_center, end_center) in projectile_data.iter() {
+        for (proj_entity, start_center, end_center, size, damage) in projectile_data.iter() {
             if consumed.contains(proj_entity) {
                 continue;
             }
@@ -77,7 +79,7 @@ pub fn enemy_c_hit_system(
             if let Some(hit_center) = swept_projectile_hit_center(
                 *start_center,
                 *end_center,
-                Vec2::splat(PROJECTILE_SIZE),
+                *size,
                 enemy_min,
                 enemy_size,
             ) {
@@ -88,7 +90,7 @@ pub fn enemy_c_hit_system(
                 });
                 commands.entity(*proj_entity).despawn();
 
-                let defeated = hp.take_hit();
+                let defeated = health.apply(*damage);
 
                 if defeated {
                     state.state = EnemyCStateKind::Dying;
@@ -112,6 +114,10 @@ pub fn enemy_c_hit_system(
                         transform.translation.z + 0.1,
                     );
                     spawn_explosion_b(&mut commands, &game_assets, explosion_pos);
+                    commands.send_event(EnemyDefeatedEvent {
+                        kind: EnemyKind::EnemyC,
+                        position: explosion_pos,
+                    });
 
                     play_sfx_once(
                         &mut commands,
@@ -5,6 +5,7 @@ pub mod config;
 pub mod death_system;
 pub mod hit_system;
 pub mod movement_system;
+pub mod plugin;
 pub mod spawn_system;
 
 pub use animation_system::enemy_c_animation_system;
@@ -12,4 +13,5 @@ pub use behavior_system::enemy_c_behavior_system;
 pub use death_system::enemy_c_death_system;
 pub use hit_system::enemy_c_hit_system;
 pub use movement_system::enemy_c_movement_system;
+pub use plugin::EnemyCPlugin;
 pub use spawn_system::enemy_c_dynamic_spawn_system;
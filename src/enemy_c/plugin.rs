@@ -0,0 +1,27 @@
+use super::*;
+use crate::projectile::projectile_movement_system;
+use crate::spawn::edge_spawn_system;
+use bevy::prelude::*;
+
+pub struct EnemyCPlugin;
+
+impl Plugin for EnemyCPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (
+                enemy_c_dynamic_spawn_system.after(edge_spawn_system),
+                enemy_c_behavior_system.after(edge_spawn_system),
+                enemy_c_movement_system.after(enemy_c_behavior_system),
+                enemy_c_animation_system.after(enemy_c_movement_system),
+            ),
+        )
+        .add_systems(
+            FixedUpdate,
+            (
+                enemy_c_hit_system.after(projectile_movement_system),
+                enemy_c_death_system.after(enemy_c_hit_system),
+            ),
+        );
+    }
+}
@@ -2,6 +2,7 @@ use crate::assets::GameAssets;
 use crate::components::MainCamera;
 use crate::constants::{GROUND_RECT_HEIGHT, SCREEN_HEIGHT, SCREEN_WIDTH, Z_ENEMY_BASE};
 use crate::game_state::{GamePhase, GameState};
+use crate::rng::GameRng;
 use crate::spawn::{ScreenEdge, SpawnedFromEdge};
 use bevy::prelude::*;
 
@@ -20,6 +21,7 @@ pub fn enemy_c_dynamic_spawn_system(
     camera_q: Query<&Transform, With<MainCamera>>,
     enemy_c_q: Query<&Transform, With<EnemyC>>,
     game_assets: Res<GameAssets>,
+    mut rng: ResMut<GameRng>,
 ) {
     if game_state.phase() != GamePhase::LabFight {
         *timer = ENEMY_C_CONSTANTS.dynamic_spawn_interval;
@@ -53,7 +55,7 @@ pub fn enemy_c_dynamic_spawn_system(
         return;
     }
 
-    let spawn_edge = if fastrand::bool() {
+    let spawn_edge = if rng.bool() {
         ScreenEdge::Left
     } else {
         ScreenEdge::Right
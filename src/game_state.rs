@@ -1,7 +1,10 @@
 use bevy::prelude::*;
 use bevy::time::Timer;
+use serde::{Deserialize, Serialize};
+use serde::de::IntoDeserializer;
+use serde::de::value::{Error, StrDeserializer};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GamePhase {
     HangarEntering,
     HangarFight,
@@ -16,6 +19,14 @@ impl GamePhase {
     fn default() -> Self {
         GamePhase::HangarEntering
     }
+
+    /// Parses the variant name, as sent to the page in `gunday-phase` events.
+    /// Uses the derived `Deserialize`, so it accepts exactly the names the
+    /// derived `Serialize` sends.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name: StrDeserializer<'_, Error> = name.into_deserializer();
+        GamePhase::deserialize(name).ok()
+    }
 }
 
 #[derive(Resource, Debug, Clone, Copy)]
@@ -116,7 +127,7 @@ impl Plugin for GameStatePlugin {
             .add_event::<GamePhaseRequest>()
             .add_event::<GamePhaseChanged>()
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     handle_game_phase_requests,
                     process_game_phase_transition_timers,
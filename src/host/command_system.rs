@@ -0,0 +1,46 @@
+use super::components::{HostCommand, HostCommands, take_pending};
+use crate::app_state::AppState;
+use crate::audio::{AudioMixer, MixerBus};
+use crate::difficulty::Difficulty;
+use crate::game_state::GamePhaseTransitionTimer;
+use crate::pause::components::PauseState;
+use bevy::prelude::*;
+
+pub fn collect_host_commands_system(mut commands: ResMut<HostCommands>) {
+    commands.queue.extend(take_pending());
+}
+
+/// Pausing and phase skips only make sense during a run and are dropped
+/// anywhere else; volume and difficulty apply at any time.
+pub fn apply_host_commands_system(
+    mut commands: Commands,
+    mut host: ResMut<HostCommands>,
+    app_state: Res<State<AppState>>,
+    mut mixer: ResMut<AudioMixer>,
+    mut difficulty: ResMut<Difficulty>,
+    mut pause: ResMut<NextState<PauseState>>,
+) {
+    let playing = *app_state == AppState::Playing;
+
+    for command in host.queue.drain(..) {
+        match command {
+            HostCommand::Pause if playing => pause.set(PauseState::Paused),
+            HostCommand::Resume if playing => pause.set(PauseState::Running),
+            HostCommand::SetMasterVolume(level) => {
+                mixer.set_volume(MixerBus::Master, level);
+            }
+            HostCommand::SetDifficulty(next) => {
+                *difficulty = next;
+            }
+            // Gameplay reads phase requests in `FixedUpdate`, a timer keeps
+            // the request around until it runs.
+            HostCommand::SkipToPhase(phase) if playing => {
+                commands.spawn(GamePhaseTransitionTimer::new(phase, 0.0));
+            }
+            HostCommand::ToggleMute => {
+                mixer.muted = !mixer.muted;
+            }
+            HostCommand::Pause | HostCommand::Resume | HostCommand::SkipToPhase(_) => {}
+        }
+    }
+}
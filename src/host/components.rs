@@ -0,0 +1,37 @@
+use crate::difficulty::Difficulty;
+use crate::game_state::GamePhase;
+use bevy::prelude::*;
+use std::sync::Mutex;
+
+/// Commands from the embedding page, see the exports in `lib.rs`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HostCommand {
+    Pause,
+    Resume,
+    SetMasterVolume(f32),
+    SetDifficulty(Difficulty),
+    SkipToPhase(GamePhase),
+    ToggleMute,
+}
+
+/// Exports run outside the app, so commands wait here until the next frame.
+static PENDING: Mutex<Vec<HostCommand>> = Mutex::new(Vec::new());
+
+pub fn enqueue(command: HostCommand) {
+    if let Ok(mut pending) = PENDING.lock() {
+        pending.push(command);
+    }
+}
+
+pub(crate) fn take_pending() -> Vec<HostCommand> {
+    PENDING
+        .lock()
+        .map(|mut pending| std::mem::take(&mut *pending))
+        .unwrap_or_default()
+}
+
+/// This frame's commands, in the order the page sent them.
+#[derive(Resource, Default)]
+pub struct HostCommands {
+    pub queue: Vec<HostCommand>,
+}
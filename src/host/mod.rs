@@ -0,0 +1,6 @@
+pub mod command_system;
+pub mod components;
+pub mod plugin;
+
+pub use components::{HostCommand, enqueue};
+pub use plugin::HostPlugin;
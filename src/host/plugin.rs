@@ -0,0 +1,15 @@
+use super::command_system::{apply_host_commands_system, collect_host_commands_system};
+use super::components::HostCommands;
+use bevy::prelude::*;
+
+/// Lets the embedding page pause, mute, retune and skip through the game.
+pub struct HostPlugin;
+
+impl Plugin for HostPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HostCommands>().add_systems(
+            Update,
+            (collect_host_commands_system, apply_host_commands_system).chain(),
+        );
+    }
+}
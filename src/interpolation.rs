@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+/// Blends the rendered translation between the last two fixed simulation
+/// steps. Simulation systems always see the stepped translation; it is put
+/// back at the start of every frame.
+#[derive(Component, Default)]
+pub struct TransformInterpolation {
+    previous: Option<Vec3>,
+    current: Option<Vec3>,
+}
+
+pub struct InterpolationPlugin;
+
+impl Plugin for InterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(First, restore_simulated_translation)
+            .add_systems(FixedFirst, store_previous_translation)
+            .add_systems(FixedLast, store_current_translation)
+            .add_systems(
+                RunFixedMainLoop,
+                interpolate_rendered_translation.in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+            );
+    }
+}
+
+fn restore_simulated_translation(mut q: Query<(&mut Transform, &TransformInterpolation)>) {
+    for (mut transform, interpolation) in q.iter_mut() {
+        if let Some(current) = interpolation.current {
+            transform.translation = current;
+        }
+    }
+}
+
+fn store_previous_translation(mut q: Query<(&Transform, &mut TransformInterpolation)>) {
+    for (transform, mut interpolation) in q.iter_mut() {
+        interpolation.previous = Some(transform.translation);
+    }
+}
+
+fn store_current_translation(mut q: Query<(&Transform, &mut TransformInterpolation)>) {
+    for (transform, mut interpolation) in q.iter_mut() {
+        interpolation.current = Some(transform.translation);
+    }
+}
+
+fn interpolate_rendered_translation(
+    fixed_time: Res<Time<Fixed>>,
+    mut q: Query<(&mut Transform, &mut TransformInterpolation)>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+    for (mut transform, mut interpolation) in q.iter_mut() {
+        let Some(current) = interpolation.current else {
+            continue;
+        };
+
+        // Moved outside the fixed loop (level reset, respawn): snap instead of blending.
+        if transform.translation != current {
+            interpolation.previous = Some(transform.translation);
+            interpolation.current = Some(transform.translation);
+            continue;
+        }
+
+        let previous = interpolation.previous.unwrap_or(current);
+        transform.translation = previous.lerp(current, alpha);
+    }
+}
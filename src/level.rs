@@ -0,0 +1,408 @@
+use crate::assets::{GameAssets, LevelImages, level_image_chunk_count, spawn_level_layers};
+use crate::boss::components::{Boss, BossCannon, ExplodingPart};
+use crate::checkpoint::Checkpoints;
+use crate::components::{
+    CameraState, LayerGeometry, LayerGeometryStorage, LevelEntity, WorldBounds,
+};
+use crate::constants::SCREEN_HEIGHT;
+use crate::effects::explosion_anim::Explosion;
+use crate::enemy_a::robot_components::EnemyRobot;
+use crate::enemy_b::components::{EnemyB, Grenade};
+use crate::enemy_c::components::EnemyC;
+use crate::game_state::{GamePhase, GamePhaseRequest, GamePhaseTransitionTimer};
+use crate::miniboss::components::{Miniboss, MinibossGrenade};
+use crate::pickup::{Pickup, PickupKind, spawn_pickup};
+use crate::player::components::{Player, Velocity};
+use crate::player::setup_player::player_spawn_translation;
+use crate::player::track_player_position_system::{PositionTrigger, PositionTriggerState};
+use crate::projectile::components::Projectile;
+use crate::restart::GameRestartRequested;
+use crate::soundtrack::TrackSetName;
+use crate::spawn::{EdgeSpawnDefinition, EdgeSpawnManager, ScreenEdge};
+use crate::systems::{PlayerControl, WinMusic, initial_camera_x, spawn_layer_geometry};
+use crate::weapon::{WeaponKind, WeaponPickup, spawn_weapon_pickup};
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Level files in the order they are played.
+pub const CAMPAIGN_LEVELS: &[&str] = &["levels/level.level.ron"];
+
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+pub struct LevelData {
+    pub width: f32,
+    pub background: String,
+    pub foreground: String,
+    pub solids: Vec<SolidData>,
+    pub edge_spawns: Vec<EdgeSpawnData>,
+    pub triggers: Vec<TriggerData>,
+    #[serde(default)]
+    pub camera_lock_x: Option<f32>,
+    #[serde(default)]
+    pub weapon_pickups: Vec<WeaponPickupData>,
+    #[serde(default)]
+    pub pickups: Vec<PickupData>,
+    /// Player x positions that save progress for a continue.
+    #[serde(default)]
+    pub checkpoints: Vec<f32>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct SolidData {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub enum EdgeSpawnData {
+    EnemyB { x: f32, ground_y: f32 },
+    InfectedEnemyA { x: f32, edge: ScreenEdge },
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TriggerData {
+    pub x: f32,
+    #[serde(default)]
+    pub track_set: Option<TrackSetName>,
+    #[serde(default)]
+    pub phase: Option<GamePhase>,
+}
+
+/// A weapon lying in the level. `y` is measured from the bottom of the level,
+/// like solids.
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct WeaponPickupData {
+    pub x: f32,
+    pub y: f32,
+    pub kind: WeaponKind,
+}
+
+/// A coin or bonus item placed in the level, positioned like weapon pickups.
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct PickupData {
+    pub x: f32,
+    pub y: f32,
+    pub kind: PickupKind,
+}
+
+#[derive(Default)]
+pub struct LevelDataLoader;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LevelDataLoaderError {
+    #[error("could not read level file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse level file: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("level image {0:?} is not in levels/")]
+    UnknownImage(String),
+}
+
+impl AssetLoader for LevelDataLoader {
+    type Asset = LevelData;
+    type Settings = ();
+    type Error = LevelDataLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<LevelData, LevelDataLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let level: LevelData = ron::de::from_bytes(&bytes)?;
+        for image in [&level.background, &level.foreground] {
+            if level_image_chunk_count(image).is_none() {
+                return Err(LevelDataLoaderError::UnknownImage(image.clone()));
+            }
+        }
+        Ok(level)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["level.ron"]
+    }
+}
+
+#[derive(Resource)]
+pub struct CurrentLevel {
+    pub handle: Handle<LevelData>,
+    pub applied: bool,
+}
+
+#[derive(Resource)]
+pub struct Campaign {
+    levels: &'static [&'static str],
+    current: usize,
+}
+
+impl Default for Campaign {
+    fn default() -> Self {
+        Self::new(CAMPAIGN_LEVELS)
+    }
+}
+
+impl Campaign {
+    /// A campaign over `levels` instead of `CAMPAIGN_LEVELS`. Insert it before
+    /// `GamePlugin` to replace the default one.
+    pub fn new(levels: &'static [&'static str]) -> Self {
+        Self { levels, current: 0 }
+    }
+
+    pub fn level_path(&self) -> &'static str {
+        self.levels[self.current]
+    }
+
+    pub fn is_final_level(&self) -> bool {
+        self.current + 1 >= self.levels.len()
+    }
+}
+
+/// Sent when the level's boss is beaten and the campaign has more levels.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct LevelCompleted;
+
+/// Sent once a level file has loaded and its world is being built.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct LevelStarted;
+
+pub(crate) type LevelScoped = Or<(
+    With<LevelEntity>,
+    With<EnemyRobot>,
+    With<EnemyB>,
+    With<Grenade>,
+    With<EnemyC>,
+    With<Miniboss>,
+    With<MinibossGrenade>,
+    With<Boss>,
+    With<BossCannon>,
+    With<ExplodingPart>,
+    With<Projectile>,
+    With<Explosion>,
+    With<GamePhaseTransitionTimer>,
+    With<WeaponPickup>,
+    With<Pickup>,
+)>;
+
+pub struct LevelPlugin;
+
+impl Plugin for LevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<LevelData>()
+            .init_asset_loader::<LevelDataLoader>()
+            .init_resource::<Campaign>()
+            .init_resource::<LevelImages>()
+            .add_event::<LevelCompleted>()
+            .add_event::<LevelStarted>()
+            .add_systems(Startup, load_level)
+            .add_systems(
+                PreUpdate,
+                (
+                    apply_loaded_level,
+                    build_level_world,
+                    configure_level_encounters,
+                    configure_level_checkpoints,
+                    reset_level_actors,
+                )
+                    .chain(),
+            )
+            .add_systems(FixedUpdate, advance_campaign_system)
+            .add_systems(Update, restart_campaign_system);
+    }
+}
+
+fn load_level(mut commands: Commands, asset_server: Res<AssetServer>, campaign: Res<Campaign>) {
+    commands.insert_resource(CurrentLevel {
+        handle: asset_server.load(campaign.level_path()),
+        applied: false,
+    });
+}
+
+fn apply_loaded_level(
+    mut current: ResMut<CurrentLevel>,
+    levels: Res<Assets<LevelData>>,
+    mut started: EventWriter<LevelStarted>,
+) {
+    if current.applied || !levels.contains(&current.handle) {
+        return;
+    }
+    current.applied = true;
+    started.write(LevelStarted);
+}
+
+fn started_level<'a>(
+    started: &mut EventReader<LevelStarted>,
+    current: &CurrentLevel,
+    levels: &'a Assets<LevelData>,
+) -> Option<&'a LevelData> {
+    started.read().last()?;
+    levels.get(&current.handle)
+}
+
+fn build_level_world(
+    mut commands: Commands,
+    mut started: EventReader<LevelStarted>,
+    current: Res<CurrentLevel>,
+    levels: Res<Assets<LevelData>>,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut geometry_storage: ResMut<LayerGeometryStorage>,
+) {
+    let Some(level) = started_level(&mut started, &current, &levels) else {
+        return;
+    };
+
+    commands.insert_resource(WorldBounds { width: level.width });
+
+    let level_images = LevelImages::load(&asset_server, &level.background, &level.foreground);
+    spawn_level_layers(&mut commands, &level_images);
+    commands.insert_resource(level_images);
+
+    geometry_storage.objects = level
+        .solids
+        .iter()
+        .map(|solid| LayerGeometry::new_rectangle(solid.x, solid.y, solid.width, solid.height))
+        .collect();
+    spawn_layer_geometry(&mut commands, &mut meshes, &geometry_storage);
+}
+
+fn configure_level_encounters(
+    mut commands: Commands,
+    mut started: EventReader<LevelStarted>,
+    current: Res<CurrentLevel>,
+    levels: Res<Assets<LevelData>>,
+    mut spawn_manager: ResMut<EdgeSpawnManager>,
+    mut trigger_state: ResMut<PositionTriggerState>,
+    game_assets: Res<GameAssets>,
+) {
+    let Some(level) = started_level(&mut started, &current, &levels) else {
+        return;
+    };
+
+    spawn_manager.definitions = level
+        .edge_spawns
+        .iter()
+        .map(|spawn| match *spawn {
+            EdgeSpawnData::EnemyB { x, ground_y } => EdgeSpawnDefinition::enemy_b(x, ground_y),
+            EdgeSpawnData::InfectedEnemyA { x, edge } => {
+                EdgeSpawnDefinition::infected_enemy_robot(x, edge)
+            }
+        })
+        .collect();
+
+    *trigger_state = PositionTriggerState {
+        triggers: level
+            .triggers
+            .iter()
+            .map(|trigger| PositionTrigger {
+                x: trigger.x,
+                track_set: trigger.track_set.clone(),
+                phase: trigger.phase,
+                fired: false,
+            })
+            .collect(),
+        camera_lock_x: level.camera_lock_x,
+        camera_locked: false,
+    };
+
+    for pickup in &level.weapon_pickups {
+        let position = Vec2::new(pickup.x, pickup.y - SCREEN_HEIGHT * 0.5);
+        let entity = spawn_weapon_pickup(&mut commands, &game_assets, pickup.kind, position, None);
+        commands.entity(entity).insert(LevelEntity);
+    }
+
+    for pickup in &level.pickups {
+        let position = Vec2::new(pickup.x, pickup.y - SCREEN_HEIGHT * 0.5);
+        let entity = spawn_pickup(&mut commands, &game_assets, pickup.kind, position, None);
+        commands.entity(entity).insert(LevelEntity);
+    }
+}
+
+fn configure_level_checkpoints(
+    mut started: EventReader<LevelStarted>,
+    current: Res<CurrentLevel>,
+    levels: Res<Assets<LevelData>>,
+    mut checkpoints: ResMut<Checkpoints>,
+) {
+    let Some(level) = started_level(&mut started, &current, &levels) else {
+        return;
+    };
+    checkpoints.reset(level.checkpoints.iter().copied());
+}
+
+fn reset_level_actors(
+    mut started: EventReader<LevelStarted>,
+    world: Res<WorldBounds>,
+    mut player_q: Query<(&mut Transform, &mut Velocity), With<Player>>,
+    mut camera_q: Query<(&mut Transform, &mut CameraState), Without<Player>>,
+    mut control: ResMut<PlayerControl>,
+    mut win: ResMut<WinMusic>,
+) {
+    if started.read().last().is_none() {
+        return;
+    }
+
+    let spawn = player_spawn_translation();
+    for (mut transform, mut velocity) in player_q.iter_mut() {
+        transform.translation = spawn;
+        velocity.x = 0.0;
+        velocity.y = 0.0;
+    }
+
+    let camera_x = initial_camera_x(world.width);
+    for (mut transform, mut camera_state) in camera_q.iter_mut() {
+        transform.translation.x = camera_x;
+        *camera_state = CameraState {
+            current_x: camera_x,
+            max_reached_x: camera_x,
+            lock_position: None,
+        };
+    }
+
+    control.enabled = true;
+    win.0 = false;
+}
+
+fn advance_campaign_system(
+    mut commands: Commands,
+    mut completed: EventReader<LevelCompleted>,
+    mut campaign: ResMut<Campaign>,
+    mut current: ResMut<CurrentLevel>,
+    asset_server: Res<AssetServer>,
+    level_scoped: Query<Entity, LevelScoped>,
+    mut phase_requests: EventWriter<GamePhaseRequest>,
+) {
+    if completed.read().last().is_none() || campaign.is_final_level() {
+        return;
+    }
+
+    for entity in level_scoped.iter() {
+        commands.entity(entity).try_despawn();
+    }
+
+    campaign.current += 1;
+    current.handle = asset_server.load(campaign.level_path());
+    current.applied = false;
+    phase_requests.write(GamePhaseRequest {
+        next: GamePhase::HangarEntering,
+    });
+}
+
+/// Goes back to the first level; `restart` clears out the old world.
+fn restart_campaign_system(
+    mut restart: EventReader<GameRestartRequested>,
+    mut campaign: ResMut<Campaign>,
+    mut current: ResMut<CurrentLevel>,
+    asset_server: Res<AssetServer>,
+) {
+    if restart.read().last().is_none() {
+        return;
+    }
+
+    campaign.current = 0;
+    current.handle = asset_server.load(campaign.level_path());
+    current.applied = false;
+}
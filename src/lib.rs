@@ -1,82 +1,65 @@
-use crate::player::player_jump_anim_system::player_jump_anim_system;
 use bevy::app::AppExit;
 use bevy::asset::AssetPlugin;
 use bevy::prelude::*;
 use bevy::render::texture::ImagePlugin;
-use player::player_run_anim_system::player_run_anim_system;
 use std::sync::atomic::{AtomicBool, Ordering};
 use wasm_bindgen::prelude::*;
 
+mod app_state;
 mod assets;
+pub mod attestation;
 pub mod audio;
 mod boss;
+mod bridge;
+mod checkpoint;
 mod collision;
-mod components;
-mod constants;
+pub mod components;
+pub mod constants;
+mod difficulty;
 mod effects;
 mod enemy_a;
 mod enemy_b;
 mod enemy_c;
-mod game_state;
+pub mod game_state;
+mod host;
+mod interpolation;
+pub mod level;
 mod miniboss;
+mod pause;
+pub mod pickup;
 mod player;
 mod projectile;
+mod restart;
+pub mod replay;
+pub mod rng;
+pub mod score;
 mod soundtrack;
 mod spawn;
+mod storage;
 mod systems;
+pub mod weapon;
 
 static STOP_REQUESTED: AtomicBool = AtomicBool::new(false);
+static RESTART_REQUESTED: AtomicBool = AtomicBool::new(false);
 
-use audio::setup_audio_emitters;
-use player::{
-    camera_follow_system, player_collider_resize_system, player_collision_system,
-    player_damage_system, player_enemy_contact_damage_system, player_enemy_projectile_hit_system,
-    player_game_over_system, player_gravity_system, player_hearts_update_system, player_input_system,
-    player_invincibility_blink_system, player_invincibility_system, player_movement_system,
-    player_prone_system, player_respawn_system, player_shooting_system,
-    player_sprite_offset_system, player_win_pose_system, setup_player, setup_player_hearts_ui,
-    track_player_position_system,
-};
-use soundtrack::SoundtrackPlugin;
-use systems::{setup_camera, setup_layer_geometry};
 use assets::{
     ForegroundLayer, LevelBackground, ParallaxBackground, load_game_assets,
     parallax_movement_system, position_level_background, setup_level_background,
 };
-use components::LayerGeometryStorage;
-use constants::{SCREEN_HEIGHT, SCREEN_WIDTH};
-use enemy_a::{
-    enemy_robot_animation_system, enemy_robot_behavior_system, enemy_robot_death_anim_system,
-    enemy_robot_despawn_system, enemy_robot_hit_system, enemy_robot_projectile_system,
-    enemy_spawn_protection_system,
-};
-use enemy_b::{
-    enemy_b_animation_system, enemy_b_behavior_system, enemy_b_death_anim_system,
-    enemy_b_explosion_anim_system, enemy_b_grenade_collision_system,
-    enemy_b_grenade_movement_system, enemy_b_hit_system,
-};
-use enemy_c::{
-    enemy_c_animation_system, enemy_c_behavior_system, enemy_c_death_system,
-    enemy_c_dynamic_spawn_system, enemy_c_hit_system, enemy_c_movement_system,
+use bridge::backend::EventBridge;
+use bridge::event::BridgeEvent;
+use components::{
+    EnemyDefeatedEvent, LayerGeometryStorage, PickupCollectedEvent, QuitRequested, WorldBounds,
 };
+use constants::{SCREEN_HEIGHT, SCREEN_WIDTH, SIMULATION_HZ};
+use difficulty::Difficulty;
+use game_state::GamePhase;
 use game_state::GameStatePlugin;
-use miniboss::{
-    MinibossFireEvent, miniboss_animation_system, miniboss_behavior_system, miniboss_death_system,
-    miniboss_grenade_collision_system, miniboss_grenade_fire_system,
-    miniboss_grenade_physics_system, miniboss_hit_system, miniboss_movement_system,
-    spawn_miniboss_on_phase_start,
-};
-use player::player_sprite_flip_system::player_sprite_flip_system;
-use projectile::projectile_fx_systems::projectile_hit_anim_update_system;
-use projectile::projectile_spawning_system::one_shot_lifetime_system;
-use projectile::{
-    projectile_hit_fx_system, projectile_movement_system, projectile_shoot_fx_flash_system,
-    projectile_shoot_fx_projectile_system,
-};
-use spawn::{
-    EdgeSpawnManager, configure_default_spawns, edge_spawn_system, enemy_edge_cleanup_system,
-    hangar_enemy_spawn_system,
-};
+use host::{HostCommand, enqueue};
+use interpolation::InterpolationPlugin;
+use rng::GameRng;
+use soundtrack::SoundtrackPlugin;
+use systems::setup_camera;
 
 #[wasm_bindgen]
 pub fn run_app() {
@@ -88,8 +71,74 @@ pub fn request_stop() {
     STOP_REQUESTED.store(true, Ordering::SeqCst);
 }
 
+/// Starts the game over from the first level without reloading the module.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn restart_game() {
+    RESTART_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+/// Pauses a run as if the player pressed Esc. Ignored outside a run.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn pause_game() {
+    enqueue(HostCommand::Pause);
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn resume_game() {
+    enqueue(HostCommand::Resume);
+}
+
+/// `volume` is linear, from 0 to 1. Returns false for NaN or infinity.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn set_master_volume(volume: f32) -> bool {
+    if !volume.is_finite() {
+        return false;
+    }
+    enqueue(HostCommand::SetMasterVolume(volume));
+    true
+}
+
+/// `easy`, `normal` or `hard`. Returns false for anything else.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn set_difficulty(name: &str) -> bool {
+    let Some(difficulty) = Difficulty::from_name(name) else {
+        return false;
+    };
+    enqueue(HostCommand::SetDifficulty(difficulty));
+    true
+}
+
+/// Takes a phase name as sent in `gunday-phase` events. Returns false for an
+/// unknown name. The player is not moved, only the phase changes.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn skip_to_phase(name: &str) -> bool {
+    let Some(phase) = GamePhase::from_name(name) else {
+        return false;
+    };
+    enqueue(HostCommand::SkipToPhase(phase));
+    true
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn toggle_mute() {
+    enqueue(HostCommand::ToggleMute);
+}
+
+/// Kept for pages that report a result themselves. Only the game can sign a
+/// run, so the `gunday-result` event never carries a `code`.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn send_game_result(win: bool, score: u32) {
+    EventBridge.send(BridgeEvent::Result {
+        win,
+        score,
+        code: None,
+    });
+}
+
 pub fn main() {
     STOP_REQUESTED.store(false, Ordering::SeqCst);
+    RESTART_REQUESTED.store(false, Ordering::SeqCst);
+    host::components::take_pending();
 
     let mut app = App::new();
 
@@ -113,212 +162,108 @@ pub fn main() {
             }),
     );
 
+    #[cfg(not(target_arch = "wasm32"))]
+    replay::configure_from_env(&mut app);
+
     app.insert_resource(ClearColor(Color::srgb(0.0, 0.0, 0.0)))
-        .insert_resource(LayerGeometryStorage::default())
-        .insert_resource(crate::player::components::PlayerActions::default())
-        .insert_resource(player::track_player_position_system::PositionTriggerState::default())
-        .insert_resource(EdgeSpawnManager::default())
-        .insert_resource(enemy_a::InfectedEnemyConfig::default())
-        .add_plugins(GameStatePlugin)
-        .add_plugins(boss::BossPlugin)
-        .add_plugins(SoundtrackPlugin)
-        .add_event::<crate::projectile::components::ProjectileHitEvent>()
-        .add_event::<MinibossFireEvent>()
-        .add_event::<player::PlayerDamagedEvent>()
-        .add_systems(
-            Startup,
-            (
-                load_game_assets,
-                setup_camera,
-                setup_layer_geometry,
-                setup_player.after(load_game_assets),
-                setup_player_hearts_ui
-                    .after(setup_camera)
-                    .after(load_game_assets),
-                setup_level_background.after(load_game_assets),
-                configure_default_spawns.after(setup_layer_geometry),
-                setup_audio_emitters.after(load_game_assets),
-            ),
-        )
-        .add_systems(
-            PreUpdate,
-            player::player_input_system::gather_player_actions,
-        )
-        .add_systems(
-            Update,
-            (
-                player_gravity_system,
-                player_input_system,
-                player_movement_system,
-            ),
-        )
-        .add_systems(
-            Update,
-            track_player_position_system.after(player_movement_system),
-        )
-        .add_systems(
-            Update,
-            (
-                player_collider_resize_system.after(player_movement_system),
-                player_collision_system.after(player_collider_resize_system),
-            ),
-        )
-        .add_systems(
-            Update,
-            (
-                player_enemy_projectile_hit_system.after(projectile_movement_system),
-                player_enemy_contact_damage_system
-                    .after(player_enemy_projectile_hit_system)
-                    .after(enemy_robot_behavior_system)
-                    .after(enemy_b_behavior_system)
-                    .after(enemy_c_movement_system)
-                    .after(miniboss_movement_system),
-                player_damage_system.after(player_enemy_contact_damage_system),
-                player_prone_system.after(player_damage_system),
-                player_game_over_system.after(player_prone_system),
-                player_invincibility_system.after(player_damage_system),
-                player_respawn_system.after(player_damage_system),
-                player_invincibility_blink_system.after(player_invincibility_system),
-                player_hearts_update_system.after(player_prone_system),
-            ),
-        )
-        .add_systems(
-            Update,
-            (
-                camera_follow_system,
-                player_shooting_system,
-                player_jump_anim_system,
-                player_run_anim_system,
-            ),
-        )
-        .add_systems(
-            Update,
-            player_win_pose_system
-                .after(player_run_anim_system)
-                .after(player_shooting_system)
-                .after(player_jump_anim_system),
-        )
-        .add_systems(
-            Update,
-            (
-                player_sprite_flip_system.after(player_win_pose_system),
-                player_sprite_offset_system.after(player_win_pose_system),
-            ),
-        )
-        .add_systems(Update, projectile_movement_system)
-        .add_systems(
-            Update,
-            (
-                projectile_shoot_fx_projectile_system.after(player_shooting_system),
-                projectile_shoot_fx_flash_system.after(player_shooting_system),
-            ),
-        )
-        .add_systems(
-            Update,
-            projectile_hit_fx_system
-                .after(projectile_movement_system)
-                .after(enemy_robot_hit_system)
-                .after(enemy_c_hit_system)
-                .after(miniboss_hit_system),
-        )
-        .add_systems(Update, effects::explosion_anim::explosion_anim_system)
-        .add_systems(
-            Update,
-            (projectile_hit_anim_update_system, one_shot_lifetime_system),
-        )
-        .add_systems(
-            Update,
-            (
-                hangar_enemy_spawn_system,
-                edge_spawn_system,
-                enemy_c_dynamic_spawn_system.after(edge_spawn_system),
-                enemy_spawn_protection_system.after(edge_spawn_system),
-                enemy_robot_behavior_system.after(edge_spawn_system),
-                enemy_robot_animation_system.after(enemy_robot_behavior_system),
-            ),
-        )
-        .add_systems(
-            Update,
-            (
-                enemy_robot_projectile_system.after(enemy_robot_behavior_system),
-                enemy_robot_hit_system.after(projectile_movement_system),
-                enemy_a::infected_transform_system.after(enemy_robot_hit_system),
-                enemy_robot_death_anim_system.after(enemy_robot_hit_system),
-                enemy_robot_despawn_system.after(enemy_robot_death_anim_system),
-            ),
-        )
-        .add_systems(
-            Update,
-            (
-                enemy_b_behavior_system.after(edge_spawn_system),
-                enemy_b_animation_system.after(enemy_b_behavior_system),
-                enemy_b::grenade_system::enemy_b_grenade_physics_system,
-                enemy_b_grenade_movement_system.after(enemy_b_behavior_system),
-                enemy_b_grenade_collision_system
-                    .after(enemy_b::grenade_system::enemy_b_grenade_physics_system),
-                enemy_b_hit_system.after(projectile_movement_system),
-                enemy_b_death_anim_system.after(enemy_b_hit_system),
-                enemy_b::hit_system::enemy_b_despawn_system.after(enemy_b_death_anim_system),
-                enemy_b::hit_system::enemy_b_spawn_protection_system.after(edge_spawn_system),
-                enemy_b_explosion_anim_system,
-            ),
-        )
-        .add_systems(
-            Update,
-            (
-                enemy_c_behavior_system.after(edge_spawn_system),
-                enemy_c_movement_system.after(enemy_c_behavior_system),
-                enemy_c_animation_system.after(enemy_c_movement_system),
-            ),
-        )
-        .add_systems(
-            Update,
-            (
-                enemy_c_hit_system.after(projectile_movement_system),
-                enemy_c_death_system.after(enemy_c_hit_system),
-            ),
-        )
-        .add_systems(
-            Update,
-            enemy_edge_cleanup_system
-                .after(enemy_c_movement_system)
-                .after(enemy_robot_behavior_system)
-                .after(enemy_b_behavior_system),
-        )
-        .add_systems(
-            Update,
-            (
-                spawn_miniboss_on_phase_start,
-                miniboss_behavior_system
-                    .after(spawn_miniboss_on_phase_start)
-                    .after(edge_spawn_system),
-                miniboss_movement_system.after(miniboss_behavior_system),
-                miniboss_animation_system.after(miniboss_behavior_system),
-                miniboss_grenade_fire_system.after(miniboss_behavior_system),
-                miniboss_grenade_physics_system.after(miniboss_grenade_fire_system),
-                miniboss_grenade_collision_system.after(miniboss_grenade_physics_system),
-                miniboss_hit_system.after(projectile_movement_system),
-                miniboss_death_system.after(miniboss_hit_system),
-            ),
-        )
-        .add_systems(
-            Update,
-            (
-                position_level_background,
-                parallax_movement_system.after(camera_follow_system),
-                pixel_perfect_snap_system.after(camera_follow_system),
-                stop_request_system,
-            ),
-        )
+        .add_plugins(GamePlugin)
         .run();
 }
 
+/// The whole game: title and results screens, level, player, weapons,
+/// pickups, enemies, bosses, score and soundtrack.
+///
+/// Gameplay runs in `FixedUpdate` at `SIMULATION_HZ`; `Update` only handles
+/// presentation.
+///
+/// Does not depend on a window or renderer, so it can be added to a
+/// `MinimalPlugins` app as long as `AssetPlugin`, `InputPlugin` and
+/// `StatesPlugin` are present and the `Image`, `Mesh` and `AudioSource` asset
+/// types are registered.
+pub struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Time::<Fixed>::from_hz(SIMULATION_HZ))
+            .init_resource::<GameRng>()
+            .init_resource::<LayerGeometryStorage>()
+            .init_resource::<WorldBounds>()
+            .add_event::<EnemyDefeatedEvent>()
+            .add_event::<PickupCollectedEvent>()
+            .add_event::<QuitRequested>()
+            .add_plugins((
+                InterpolationPlugin,
+                GameStatePlugin,
+                level::LevelPlugin,
+                player::PlayerPlugin,
+                projectile::ProjectilePlugin,
+                spawn::SpawnPlugin,
+                enemy_a::EnemyAPlugin,
+                enemy_b::EnemyBPlugin,
+                enemy_c::EnemyCPlugin,
+                miniboss::MinibossPlugin,
+                boss::BossPlugin,
+                SoundtrackPlugin,
+                replay::ReplayPlugin,
+            ))
+            .add_plugins((
+                weapon::WeaponPlugin,
+                pickup::PickupPlugin,
+                score::ScorePlugin,
+                checkpoint::CheckpointPlugin,
+                pause::PausePlugin,
+                app_state::AppStatePlugin,
+                restart::RestartPlugin,
+                bridge::BridgePlugin,
+                difficulty::DifficultyPlugin,
+                host::HostPlugin,
+                attestation::AttestationPlugin,
+                audio::GameAudioPlugin,
+            ))
+            .add_systems(
+                Startup,
+                (
+                    load_game_assets,
+                    setup_camera,
+                    setup_level_background.after(load_game_assets),
+                    effects::screen_pulse::spawn_screen_pulse,
+                ),
+            )
+            .add_systems(FixedUpdate, effects::explosion_anim::explosion_anim_system)
+            .add_systems(
+                Update,
+                (
+                    position_level_background,
+                    effects::screen_pulse::screen_pulse_system,
+                    parallax_movement_system,
+                    pixel_perfect_snap_system,
+                    stop_request_system,
+                    quit_request_system,
+                    restart_request_system,
+                ),
+            );
+    }
+}
+
 fn stop_request_system(mut exit: EventWriter<AppExit>) {
     if STOP_REQUESTED.swap(false, Ordering::SeqCst) {
         exit.write(AppExit::Success);
     }
 }
 
+/// The web build keeps running; the bridge tells the page instead.
+fn quit_request_system(mut quits: EventReader<QuitRequested>, mut exit: EventWriter<AppExit>) {
+    if quits.read().last().is_some() && cfg!(not(target_arch = "wasm32")) {
+        exit.write(AppExit::Success);
+    }
+}
+
+fn restart_request_system(mut restart: EventWriter<restart::GameRestartRequested>) {
+    if RESTART_REQUESTED.swap(false, Ordering::SeqCst) {
+        restart.write(restart::GameRestartRequested);
+    }
+}
+
 fn pixel_perfect_snap_system(
     mut q: Query<
         &mut Transform,
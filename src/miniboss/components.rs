@@ -1,12 +1,15 @@
 use super::config::MINIBOSS_CONFIG;
 use crate::assets::GameAssets;
+use crate::components::Health;
 use crate::constants::{
     DEFAULT_GRAVITY, GROUND_RECT_HEIGHT, SCREEN_HEIGHT, SCREEN_WIDTH, Z_ENEMY_BASE,
 };
+use crate::interpolation::TransformInterpolation;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
 
 #[derive(Component)]
+#[require(TransformInterpolation)]
 pub struct Miniboss;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -118,19 +121,6 @@ impl Default for MinibossAnimation {
     }
 }
 
-#[derive(Component)]
-pub struct MinibossHealth {
-    pub hp: i32,
-}
-
-impl MinibossHealth {
-    pub fn new() -> Self {
-        Self {
-            hp: MINIBOSS_CONFIG.hit_points,
-        }
-    }
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MinibossDeathPhase {
     Wait,
@@ -172,6 +162,7 @@ pub const MINIBOSS_DEATH_EXPLOSION_OFFSETS: [Vec2; 3] = [
 ];
 
 #[derive(Component, Debug)]
+#[require(TransformInterpolation)]
 pub struct MinibossGrenade {
     pub velocity: Vec2,
     pub rotation_timer: f32,
@@ -195,7 +186,7 @@ pub struct MinibossBundle {
     pub miniboss: Miniboss,
     pub behavior: MinibossBehavior,
     pub animation: MinibossAnimation,
-    pub health: MinibossHealth,
+    pub health: Health,
 }
 
 impl MinibossBundle {
@@ -203,7 +194,7 @@ impl MinibossBundle {
         image: Handle<Image>,
         position: Vec3,
         behavior: MinibossBehavior,
-        health: MinibossHealth,
+        health: Health,
     ) -> Self {
         let mut sprite = Sprite::from_image(image);
         sprite.anchor = Anchor::BottomCenter;
@@ -271,6 +262,6 @@ pub fn miniboss_bundle_at(
         game_assets.miniboss_move_a.clone(),
         position,
         behavior,
-        MinibossHealth::new(),
+        Health::new(MINIBOSS_CONFIG.hit_points),
     )
 }
@@ -6,7 +6,7 @@ pub struct MinibossConfig {
     pub height: f32,
     pub right_limit_x: f32,
     pub move_speed: f32,
-    pub hit_points: i32,
+    pub hit_points: u32,
     pub pre_volley_wait: f32,
     pub post_volley_wait: f32,
     pub death_wait: f32,
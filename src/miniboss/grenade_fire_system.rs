@@ -3,6 +3,7 @@ use super::config::MINIBOSS_CONFIG;
 use crate::assets::GameAssets;
 use crate::audio::{SfxEmitters, play_sfx_once};
 use crate::constants::{PROJECTILE_SIZE, Z_PROJECTILES};
+use crate::rng::GameRng;
 use bevy::prelude::*;
 
 pub fn miniboss_grenade_fire_system(
@@ -11,6 +12,7 @@ pub fn miniboss_grenade_fire_system(
     emitters: Res<SfxEmitters>,
     mut events: EventReader<MinibossFireEvent>,
     miniboss_q: Query<(&Transform, &MinibossBehavior)>,
+    mut rng: ResMut<GameRng>,
 ) {
     let time_of_flight = miniboss_time_of_flight();
     for event in events.read() {
@@ -34,7 +36,7 @@ pub fn miniboss_grenade_fire_system(
         );
 
         let dir = if behavior.facing_right { 1.0 } else { -1.0 };
-        let distance = fastrand::f32() * 400.0 + 200.0;
+        let distance = rng.f32() * 400.0 + 200.0;
         let vx = (distance / time_of_flight) * dir;
         let vy = MINIBOSS_CONFIG.grenade_initial_velocity_y;
 
@@ -2,12 +2,12 @@ use super::components::*;
 use super::config::MINIBOSS_CONFIG;
 use crate::assets::GameAssets;
 use crate::audio::{SfxEmitters, play_sfx_once};
-use crate::constants::PROJECTILE_SIZE;
+use crate::components::{Damage, EnemyDefeatedEvent, EnemyKind, Health};
 use crate::game_state::{GamePhase, GamePhaseTransitionTimer};
 use crate::projectile::components::{
     PlayerProjectile, Projectile, ProjectileHitEvent, swept_projectile_hit_center,
 };
-use crate::soundtrack::{SoundtrackController, TrackSetName};
+use crate::soundtrack::{SoundtrackController, Stinger, TrackSetName, play_stinger};
 use bevy::prelude::*;
 
 pub fn miniboss_hit_system(
@@ -17,7 +17,7 @@ pub fn miniboss_hit_system(
             Entity,
             &Transform,
             &mut MinibossBehavior,
-            &mut MinibossHealth,
+            &mut Health,
             &mut MinibossAnimation,
             &mut Sprite,
             Option<&MinibossDeath>,
@@ -33,13 +33,15 @@ pub fn miniboss_hit_system(
     emitters: Res<SfxEmitters>,
     mut controller: ResMut<SoundtrackController>,
 ) {
-    let projectile_data: Vec<(Entity, Vec2, Vec2)> = projectiles
+    let projectile_data: Vec<(Entity, Vec2, Vec2, Vec2, Damage)> = projectiles
         .iter()
         .map(|(entity, transform, projectile)| {
             (
                 entity,
                 projectile.previous_translation,
                 Vec2::new(transform.translation.x, transform.translation.y),
+                projectile.size,
+                projectile.damage,
             )
         })
         .collect();
@@ -56,18 +58,16 @@ pub fn miniboss_hit_system(
             transform.translation.y,
         );
         let enemy_size = Vec2::new(MINIBOSS_CONFIG.width, MINIBOSS_CONFIG.height);
-        let mut hit = false;
 
-        for (proj_entity, start_center, end_center) in projectile_data.iter() {
+        for (proj_entity, start_center, end_center, size, damage) in projectile_data.iter() {
             if let Some(hit_center) = swept_projectile_hit_center(
                 *start_center,
                 *end_center,
-                Vec2::splat(PROJECTILE_SIZE),
+                *size,
                 enemy_min,
                 enemy_size,
             ) {
-                hit = true;
-                health.hp -= 1;
+                health.apply(*damage);
                 play_sfx_once(
                     &mut commands,
                     emitters.enemy_hit,
@@ -77,7 +77,7 @@ pub fn miniboss_hit_system(
                 hit_writer.write(ProjectileHitEvent { position: hit_pos });
                 commands.entity(*proj_entity).despawn();
 
-                if health.hp <= 0 {
+                if health.is_depleted() {
                     let death_started = begin_miniboss_death(
                         &mut commands,
                         entity,
@@ -89,7 +89,12 @@ pub fn miniboss_hit_system(
                     );
 
                     if death_started {
-                        let track_name = TrackSetName::Ambient;
+                        commands.send_event(EnemyDefeatedEvent {
+                            kind: EnemyKind::Miniboss,
+                            position: transform.translation
+                                + Vec3::Y * MINIBOSS_CONFIG.height * 0.5,
+                        });
+                        let track_name = TrackSetName::AMBIENT;
                         controller.request_track_set(track_name);
 
                         commands.spawn(GamePhaseTransitionTimer::new(GamePhase::LabEntering, 0.0));
@@ -99,10 +104,6 @@ pub fn miniboss_hit_system(
                 break;
             }
         }
-
-        if hit {
-            health.hp = health.hp.max(0);
-        }
     }
 }
 
@@ -136,5 +137,6 @@ fn begin_miniboss_death(
     death.explosion_timer = 0.0;
     death.explosion_index = 0;
     commands.entity(entity).insert(death);
+    play_stinger(commands, Stinger::MinibossDown);
     true
 }
@@ -8,6 +8,7 @@ pub mod grenade_fire_system;
 pub mod grenade_physics_system;
 pub mod hit_system;
 pub mod movement_system;
+pub mod plugin;
 pub mod spawn_system;
 
 pub use animation_system::miniboss_animation_system;
@@ -20,4 +21,5 @@ pub use grenade_fire_system::miniboss_grenade_fire_system;
 pub use grenade_physics_system::miniboss_grenade_physics_system;
 pub use hit_system::miniboss_hit_system;
 pub use movement_system::miniboss_movement_system;
+pub use plugin::MinibossPlugin;
 pub use spawn_system::spawn_miniboss_on_phase_start;
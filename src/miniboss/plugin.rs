@@ -0,0 +1,27 @@
+use super::*;
+use crate::projectile::projectile_movement_system;
+use crate::spawn::edge_spawn_system;
+use bevy::prelude::*;
+
+pub struct MinibossPlugin;
+
+impl Plugin for MinibossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MinibossFireEvent>().add_systems(
+            FixedUpdate,
+            (
+                spawn_miniboss_on_phase_start,
+                miniboss_behavior_system
+                    .after(spawn_miniboss_on_phase_start)
+                    .after(edge_spawn_system),
+                miniboss_movement_system.after(miniboss_behavior_system),
+                miniboss_animation_system.after(miniboss_behavior_system),
+                miniboss_grenade_fire_system.after(miniboss_behavior_system),
+                miniboss_grenade_physics_system.after(miniboss_grenade_fire_system),
+                miniboss_grenade_collision_system.after(miniboss_grenade_physics_system),
+                miniboss_hit_system.after(projectile_movement_system),
+                miniboss_death_system.after(miniboss_hit_system),
+            ),
+        );
+    }
+}
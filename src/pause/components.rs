@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+/// Whether gameplay is frozen behind the pause menu. Only changes during a
+/// run, through `NextState<PauseState>`.
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseMenuItem {
+    Resume,
+    Restart,
+    Settings,
+    Audio,
+    Quit,
+}
+
+impl PauseMenuItem {
+    pub const ALL: [PauseMenuItem; 5] = [
+        PauseMenuItem::Resume,
+        PauseMenuItem::Restart,
+        PauseMenuItem::Settings,
+        PauseMenuItem::Audio,
+        PauseMenuItem::Quit,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PauseMenuItem::Resume => "Resume",
+            PauseMenuItem::Restart => "Restart",
+            PauseMenuItem::Settings => "Settings",
+            PauseMenuItem::Audio => "Audio",
+            PauseMenuItem::Quit => "Quit",
+        }
+    }
+}
+
+/// Selected row of the pause menu, reset every time the game pauses.
+#[derive(Resource, Default)]
+pub struct PauseMenu {
+    pub selected: usize,
+}
+
+#[derive(Component)]
+pub struct PauseMenuRoot;
+
+#[derive(Component)]
+pub struct PauseMenuRow(pub usize);
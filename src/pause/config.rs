@@ -0,0 +1,15 @@
+#[derive(Debug, Clone, Copy)]
+pub struct PauseConfig {
+    /// Soundtrack volume multiplier while paused.
+    pub music_duck: f32,
+    pub title_font_size: f32,
+    pub item_font_size: f32,
+    pub hint_font_size: f32,
+}
+
+pub const PAUSE_CONFIG: PauseConfig = PauseConfig {
+    music_duck: 0.3,
+    title_font_size: 28.0,
+    item_font_size: 20.0,
+    hint_font_size: 14.0,
+};
@@ -0,0 +1,131 @@
+use super::components::{PauseMenu, PauseMenuItem, PauseMenuRoot, PauseMenuRow, PauseState};
+use super::config::PAUSE_CONFIG;
+use crate::audio::AudioSettingsScreen;
+use crate::bridge::send_run_result;
+use crate::components::QuitRequested;
+use crate::player::rebind_screen::RebindScreen;
+use crate::restart::GameRestartRequested;
+use bevy::prelude::*;
+
+const ITEM_COLOR: Color = Color::srgb(0.7, 0.7, 0.7);
+const SELECTED_ITEM_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+/// Shows the menu with the first row selected.
+pub fn spawn_pause_menu(mut commands: Commands, mut menu: ResMut<PauseMenu>) {
+    *menu = PauseMenu::default();
+    spawn_pause_menu_root(&mut commands);
+}
+
+pub fn despawn_pause_menu(mut commands: Commands, roots: Query<Entity, With<PauseMenuRoot>>) {
+    for root in roots.iter() {
+        commands.entity(root).despawn();
+    }
+}
+
+fn spawn_pause_menu_root(commands: &mut Commands) {
+    commands
+        .spawn((
+            PauseMenuRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            GlobalZIndex(10),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("PAUSED"),
+                TextFont::from_font_size(PAUSE_CONFIG.title_font_size),
+                Node {
+                    margin: UiRect::bottom(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+            for (index, item) in PauseMenuItem::ALL.iter().enumerate() {
+                root.spawn((
+                    PauseMenuRow(index),
+                    Text::new(item.label()),
+                    TextFont::from_font_size(PAUSE_CONFIG.item_font_size),
+                    TextColor(ITEM_COLOR),
+                ));
+            }
+            root.spawn((
+                Text::new("Enter/A: select   Esc/Start: resume"),
+                TextFont::from_font_size(PAUSE_CONFIG.hint_font_size),
+                TextColor(ITEM_COLOR),
+                Node {
+                    margin: UiRect::top(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+pub fn pause_menu_navigation_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut menu: ResMut<PauseMenu>,
+    (mut rebind, mut audio_settings): (ResMut<RebindScreen>, ResMut<AudioSettingsScreen>),
+    mut next: ResMut<NextState<PauseState>>,
+    mut restart: EventWriter<GameRestartRequested>,
+) {
+    // The settings screens opened from here take over the same keys.
+    if rebind.open || audio_settings.open {
+        return;
+    }
+
+    let pressed = |key: KeyCode, button: GamepadButton| {
+        keyboard.just_pressed(key) || gamepads.iter().any(|pad| pad.just_pressed(button))
+    };
+
+    let count = PauseMenuItem::ALL.len();
+    if pressed(KeyCode::ArrowUp, GamepadButton::DPadUp) {
+        menu.selected = (menu.selected + count - 1) % count;
+    }
+    if pressed(KeyCode::ArrowDown, GamepadButton::DPadDown) {
+        menu.selected = (menu.selected + 1) % count;
+    }
+
+    if !pressed(KeyCode::Enter, GamepadButton::South) {
+        return;
+    }
+
+    match PauseMenuItem::ALL[menu.selected] {
+        PauseMenuItem::Resume => next.set(PauseState::Running),
+        PauseMenuItem::Restart => {
+            restart.write(GameRestartRequested);
+            next.set(PauseState::Running);
+        }
+        PauseMenuItem::Settings => rebind.request_open(),
+        PauseMenuItem::Audio => audio_settings.request_open(),
+        PauseMenuItem::Quit => {
+            // Quitting mid-run reports a loss, like running out of lives.
+            commands.queue(|world: &mut World| send_run_result(world, false));
+            commands.send_event(QuitRequested);
+        }
+    }
+}
+
+pub fn pause_menu_text_system(
+    menu: Res<PauseMenu>,
+    mut rows: Query<(&PauseMenuRow, &mut TextColor)>,
+) {
+    if !menu.is_changed() {
+        return;
+    }
+
+    for (row, mut color) in rows.iter_mut() {
+        color.0 = if row.0 == menu.selected {
+            SELECTED_ITEM_COLOR
+        } else {
+            ITEM_COLOR
+        };
+    }
+}
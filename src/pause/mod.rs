@@ -0,0 +1,7 @@
+pub mod components;
+pub mod config;
+pub mod menu_system;
+pub mod pause_system;
+pub mod plugin;
+
+pub use plugin::PausePlugin;
@@ -0,0 +1,51 @@
+use super::components::PauseState;
+use super::config::PAUSE_CONFIG;
+use crate::audio::AudioSettingsScreen;
+use crate::player::rebind_screen::RebindScreen;
+use crate::soundtrack::SoundtrackController;
+use bevy::prelude::*;
+
+/// Esc or Start toggles the pause. Ignored while a settings screen is up,
+/// the rebind screen owns Esc for cancelling a capture.
+pub fn toggle_pause_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    rebind: Res<RebindScreen>,
+    audio_settings: Res<AudioSettingsScreen>,
+    state: Res<State<PauseState>>,
+    mut next: ResMut<NextState<PauseState>>,
+) {
+    if rebind.open || audio_settings.open {
+        return;
+    }
+
+    let toggled = keyboard.just_pressed(KeyCode::Escape)
+        || gamepads
+            .iter()
+            .any(|pad| pad.just_pressed(GamepadButton::Start));
+    if !toggled {
+        return;
+    }
+
+    next.set(match state.get() {
+        PauseState::Running => PauseState::Paused,
+        PauseState::Paused => PauseState::Running,
+    });
+}
+
+/// Freezing `Time<Virtual>` stops `FixedUpdate`, which is all of gameplay.
+pub fn pause_gameplay(
+    mut time: ResMut<Time<Virtual>>,
+    mut controller: ResMut<SoundtrackController>,
+) {
+    time.pause();
+    controller.set_duck(PAUSE_CONFIG.music_duck);
+}
+
+pub fn resume_gameplay(
+    mut time: ResMut<Time<Virtual>>,
+    mut controller: ResMut<SoundtrackController>,
+) {
+    time.unpause();
+    controller.set_duck(1.0);
+}
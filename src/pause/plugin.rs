@@ -0,0 +1,33 @@
+use super::components::{PauseMenu, PauseState};
+use super::menu_system::{
+    despawn_pause_menu, pause_menu_navigation_system, pause_menu_text_system, spawn_pause_menu,
+};
+use super::pause_system::{pause_gameplay, resume_gameplay, toggle_pause_system};
+use crate::app_state::AppState;
+use bevy::prelude::*;
+
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<PauseState>()
+            .init_resource::<PauseMenu>()
+            .add_systems(
+                OnEnter(PauseState::Paused),
+                (pause_gameplay, spawn_pause_menu),
+            )
+            .add_systems(
+                OnExit(PauseState::Paused),
+                (resume_gameplay, despawn_pause_menu),
+            )
+            .add_systems(
+                Update,
+                (
+                    toggle_pause_system.run_if(in_state(AppState::Playing)),
+                    pause_menu_navigation_system.run_if(in_state(PauseState::Paused)),
+                    pause_menu_text_system.run_if(in_state(PauseState::Paused)),
+                )
+                    .chain(),
+            );
+    }
+}
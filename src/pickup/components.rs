@@ -0,0 +1,30 @@
+use super::config::PICKUP_CONFIG;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum PickupKind {
+    Coin,
+    /// Gives back a heart.
+    Bonus,
+}
+
+impl PickupKind {
+    pub fn size(self) -> Vec2 {
+        match self {
+            PickupKind::Coin => PICKUP_CONFIG.coin_size,
+            PickupKind::Bonus => PICKUP_CONFIG.bonus_size,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Pickup {
+    pub kind: PickupKind,
+    pub base_y: f32,
+    pub age: f32,
+    /// Dropped pickups vanish after a while; placed ones stay.
+    pub lifetime: Option<f32>,
+    /// Set once the player comes within the magnet radius.
+    pub attracted: bool,
+}
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PickupConfig {
+    pub coin_size: Vec2,
+    pub bonus_size: Vec2,
+    pub coin_points: u32,
+    /// Hearts given back by a bonus item.
+    pub bonus_hearts: u8,
+    pub coin_drop_chance: f32,
+    pub max_coins_dropped: usize,
+    /// Horizontal spacing between coins dropped by the same enemy.
+    pub coin_drop_spread: f32,
+    pub bonus_drop_chance: f32,
+    pub drop_lifetime: f32,
+    /// Pickups closer than this to the player fly towards them.
+    pub magnet_radius: f32,
+    pub magnet_speed: f32,
+    pub bob_amplitude: f32,
+    pub bob_speed: f32,
+}
+
+pub const PICKUP_CONFIG: PickupConfig = PickupConfig {
+    coin_size: Vec2::new(28.0, 32.0),
+    bonus_size: Vec2::new(39.0, 21.0),
+    coin_points: 50,
+    bonus_hearts: 1,
+    coin_drop_chance: 0.6,
+    max_coins_dropped: 3,
+    coin_drop_spread: 24.0,
+    bonus_drop_chance: 0.04,
+    drop_lifetime: 10.0,
+    magnet_radius: 120.0,
+    magnet_speed: 600.0,
+    bob_amplitude: 3.0,
+    bob_speed: 5.0,
+};
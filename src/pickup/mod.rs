@@ -0,0 +1,11 @@
+pub mod components;
+pub mod config;
+pub mod pickup_system;
+pub mod plugin;
+
+pub use components::{Pickup, PickupKind};
+pub use config::PICKUP_CONFIG;
+pub use pickup_system::{
+    pickup_collect_system, pickup_drop_system, pickup_motion_system, spawn_pickup,
+};
+pub use plugin::PickupPlugin;
@@ -0,0 +1,154 @@
+use super::components::{Pickup, PickupKind};
+use super::config::PICKUP_CONFIG;
+use crate::assets::GameAssets;
+use crate::audio::{SfxEmitters, play_sfx_once};
+use crate::collision::rectangles_collide;
+use crate::components::{CollectedItem, EnemyDefeatedEvent, PickupCollectedEvent};
+use crate::constants::Z_PROJECTILES;
+use crate::player::components::{Player, PlayerLives, PlayerRespawning};
+use crate::player::setup_player::SpriteSize;
+use crate::rng::GameRng;
+use crate::score::Score;
+use bevy::prelude::*;
+
+type CollectingPlayer = (With<Player>, Without<PlayerRespawning>);
+
+pub fn spawn_pickup(
+    commands: &mut Commands,
+    assets: &GameAssets,
+    kind: PickupKind,
+    position: Vec2,
+    lifetime: Option<f32>,
+) -> Entity {
+    let image = match kind {
+        PickupKind::Coin => assets.ui_coin.clone(),
+        PickupKind::Bonus => assets.ui_bonus.clone(),
+    };
+    commands
+        .spawn((
+            Sprite {
+                image,
+                custom_size: Some(kind.size()),
+                ..default()
+            },
+            Transform::from_translation(position.extend(Z_PROJECTILES)),
+            Pickup {
+                kind,
+                base_y: position.y,
+                age: 0.0,
+                lifetime,
+                attracted: false,
+            },
+        ))
+        .id()
+}
+
+pub fn pickup_drop_system(
+    mut commands: Commands,
+    mut defeated: EventReader<EnemyDefeatedEvent>,
+    mut rng: ResMut<GameRng>,
+    assets: Res<GameAssets>,
+) {
+    for event in defeated.read() {
+        let origin = event.position.truncate();
+        let lifetime = Some(PICKUP_CONFIG.drop_lifetime);
+
+        if rng.f32() < PICKUP_CONFIG.bonus_drop_chance {
+            spawn_pickup(&mut commands, &assets, PickupKind::Bonus, origin, lifetime);
+            continue;
+        }
+        if rng.f32() >= PICKUP_CONFIG.coin_drop_chance {
+            continue;
+        }
+        let count = 1 + rng.usize(0..PICKUP_CONFIG.max_coins_dropped);
+        let first = -(count as f32 - 1.0) * 0.5;
+        for i in 0..count {
+            let offset = Vec2::X * (first + i as f32) * PICKUP_CONFIG.coin_drop_spread;
+            spawn_pickup(
+                &mut commands,
+                &assets,
+                PickupKind::Coin,
+                origin + offset,
+                lifetime,
+            );
+        }
+    }
+}
+
+/// Bobs pickups in place, expires dropped ones and pulls them towards a
+/// nearby player.
+pub fn pickup_motion_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_query: Query<&Transform, CollectingPlayer>,
+    mut pickups: Query<(Entity, &mut Pickup, &mut Transform), Without<Player>>,
+) {
+    let dt = time.delta_secs();
+    let player_pos = player_query
+        .iter()
+        .next()
+        .map(|transform| transform.translation.truncate());
+
+    for (entity, mut pickup, mut transform) in pickups.iter_mut() {
+        pickup.age += dt;
+
+        let position = transform.translation.truncate();
+        if let Some(player_pos) = player_pos {
+            if position.distance(player_pos) <= PICKUP_CONFIG.magnet_radius {
+                pickup.attracted = true;
+            }
+            if pickup.attracted {
+                let step =
+                    (player_pos - position).clamp_length_max(PICKUP_CONFIG.magnet_speed * dt);
+                transform.translation += step.extend(0.0);
+                continue;
+            }
+        }
+
+        if pickup
+            .lifetime
+            .is_some_and(|lifetime| pickup.age >= lifetime)
+        {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation.y = pickup.base_y
+            + (pickup.age * PICKUP_CONFIG.bob_speed).sin() * PICKUP_CONFIG.bob_amplitude;
+    }
+}
+
+pub fn pickup_collect_system(
+    mut commands: Commands,
+    mut player_query: Query<(&Transform, &SpriteSize, &mut PlayerLives), CollectingPlayer>,
+    pickups: Query<(Entity, &Transform, &Pickup), Without<Player>>,
+    mut score: ResMut<Score>,
+    assets: Res<GameAssets>,
+    emitters: Res<SfxEmitters>,
+    mut collected: EventWriter<PickupCollectedEvent>,
+) {
+    for (player_transform, sprite_size, mut lives) in player_query.iter_mut() {
+        let player_size = Vec2::new(sprite_size.width, sprite_size.height);
+        let player_min = player_transform.translation.truncate() - player_size * 0.5;
+
+        for (entity, transform, pickup) in pickups.iter() {
+            let size = pickup.kind.size();
+            let pickup_min = transform.translation.truncate() - size * 0.5;
+            if !rectangles_collide(player_min, player_size, pickup_min, size) {
+                continue;
+            }
+            let item = match pickup.kind {
+                PickupKind::Coin => {
+                    score.add_points(PICKUP_CONFIG.coin_points);
+                    CollectedItem::Coin
+                }
+                PickupKind::Bonus => {
+                    lives.restore(PICKUP_CONFIG.bonus_hearts);
+                    CollectedItem::Bonus
+                }
+            };
+            collected.write(PickupCollectedEvent { item });
+            play_sfx_once(&mut commands, emitters.pickup, assets.coin_sfx.clone());
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use super::*;
+use crate::player::player_movement_system;
+use bevy::prelude::*;
+
+pub struct PickupPlugin;
+
+impl Plugin for PickupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (
+                pickup_drop_system,
+                pickup_motion_system.after(player_movement_system),
+                pickup_collect_system.after(pickup_motion_system),
+            ),
+        );
+    }
+}
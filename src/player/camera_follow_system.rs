@@ -1,6 +1,6 @@
 use super::components::Player;
-use crate::components::{CameraState, MainCamera};
-use crate::constants::{SCREEN_WIDTH, WORLD_WIDTH};
+use crate::components::{CameraState, MainCamera, WorldBounds};
+use crate::constants::SCREEN_WIDTH;
 use bevy::prelude::*;
 
 pub fn camera_follow_system(
@@ -9,6 +9,7 @@ pub fn camera_follow_system(
         (&mut Transform, &mut CameraState),
         (With<MainCamera>, Without<Player>),
     >,
+    world: Res<WorldBounds>,
 ) {
     let mut player_iter = player_query.iter();
     let Some(player_transform) = player_iter.next() else {
@@ -22,7 +23,7 @@ pub fn camera_follow_system(
 
     let half_screen_width = SCREEN_WIDTH / 2.0;
     let world_left_bound = half_screen_width;
-    let world_right_bound = WORLD_WIDTH - half_screen_width;
+    let world_right_bound = world.width - half_screen_width;
 
     for (mut camera_transform, mut camera_state) in camera_query.iter_mut() {
         if let Some(lock_x) = camera_state.lock_position {
@@ -16,9 +16,12 @@ pub struct PlayerShootingAnim {
 }
 use super::config::PLAYER_CONFIG;
 use crate::constants::DEFAULT_GRAVITY;
+use crate::interpolation::TransformInterpolation;
+use crate::weapon::Weapon;
 use bevy::prelude::*;
 
 #[derive(Component)]
+#[require(TransformInterpolation)]
 pub struct Player;
 
 #[derive(Component)]
@@ -36,6 +39,7 @@ pub enum PlayerSpriteKind {
     Hit,
     Fallen,
     Win,
+    Dash,
 }
 
 impl Default for PlayerSpriteKind {
@@ -79,6 +83,10 @@ impl PlayerLives {
     pub fn restore_full(&mut self, target: u8) {
         self.current = target.min(self.max);
     }
+
+    pub fn restore(&mut self, amount: u8) {
+        self.current = self.current.saturating_add(amount).min(self.max);
+    }
 }
 
 #[derive(Component)]
@@ -113,6 +121,8 @@ pub struct PlayerGameOver {
     pub sfx_played: bool,
     pub sfx_delay: f32,
     pub result_sent: bool,
+    /// The player turned down continuing from the last checkpoint.
+    pub continue_declined: bool,
 }
 
 impl PlayerGameOver {
@@ -123,6 +133,7 @@ impl PlayerGameOver {
             sfx_played: false,
             sfx_delay: 0.5,
             result_sent: false,
+            continue_declined: false,
         }
     }
 }
@@ -152,6 +163,26 @@ impl PlayerBlink {
     }
 }
 
+/// Dash in progress (`timer > 0`) and the cooldown until the next one.
+#[derive(Component, Default)]
+pub struct PlayerDash {
+    pub timer: f32,
+    pub cooldown: f32,
+    pub direction: f32,
+    pub afterimage_timer: f32,
+}
+
+impl PlayerDash {
+    pub fn is_dashing(&self) -> bool {
+        self.timer > 0.0
+    }
+}
+
+#[derive(Component)]
+pub struct DashAfterimage {
+    pub timer: f32,
+}
+
 #[derive(Component)]
 pub struct PlayerHeartsRoot;
 
@@ -178,6 +209,8 @@ pub struct PlayerBundle {
     pub shooting_anim: PlayerShootingAnim,
     pub run_anim: PlayerRunAnim,
     pub jump_anim: PlayerJumpAnim,
+    pub dash: PlayerDash,
+    pub weapon: Weapon,
 }
 
 impl PlayerBundle {
@@ -209,6 +242,8 @@ impl PlayerBundle {
             shooting_anim: PlayerShootingAnim::default(),
             run_anim: PlayerRunAnim::default(),
             jump_anim: PlayerJumpAnim::default(),
+            dash: PlayerDash::default(),
+            weapon: Weapon::default(),
         }
     }
 }
@@ -330,8 +365,45 @@ impl ActionTrigger {
     }
 }
 
+/// Input held during one simulation tick. This is the unit input replays
+/// store, so everything in `PlayerActions` must be derivable from it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ActionFrame {
+    pub buttons: u8,
+    /// Movement/aim direction quantised to `-127..=127` per axis.
+    pub stick: [i8; 2],
+}
+
+impl ActionFrame {
+    pub const JUMP: u8 = 1 << 0;
+    pub const SHOOT: u8 = 1 << 1;
+    pub const DASH: u8 = 1 << 2;
+
+    pub fn set(&mut self, button: u8, held: bool) {
+        if held {
+            self.buttons |= button;
+        } else {
+            self.buttons &= !button;
+        }
+    }
+
+    pub fn held(self, button: u8) -> bool {
+        self.buttons & button != 0
+    }
+
+    pub fn set_stick(&mut self, direction: Vec2) {
+        let quantise = |value: f32| (value.clamp(-1.0, 1.0) * 127.0).round() as i8;
+        self.stick = [quantise(direction.x), quantise(direction.y)];
+    }
+
+    pub fn stick(self) -> Vec2 {
+        Vec2::new(self.stick[0] as f32, self.stick[1] as f32) / 127.0
+    }
+}
+
 #[derive(Debug, Clone, Resource)]
 pub struct PlayerActions {
+    pub frame: ActionFrame,
     pub move_axis: f32,
     pub aim_axis: Vec2,
     pub jump: ActionTrigger,
@@ -344,6 +416,7 @@ pub struct PlayerActions {
 impl Default for PlayerActions {
     fn default() -> Self {
         Self {
+            frame: ActionFrame::default(),
             move_axis: 0.0,
             aim_axis: Vec2::ZERO,
             jump: ActionTrigger::default(),
@@ -359,4 +432,25 @@ impl PlayerActions {
     pub fn reset(&mut self) {
         *self = Self::default();
     }
+
+    pub fn apply_frame(&mut self, frame: ActionFrame, dt: f32) {
+        self.frame = frame;
+
+        let stick = frame.stick();
+        self.move_axis = stick.x;
+
+        let mut aim_axis = stick;
+        if aim_axis.length_squared() > 1.0 {
+            aim_axis = aim_axis.normalize();
+        }
+        self.aim_axis = aim_axis;
+
+        self.jump.update(frame.held(ActionFrame::JUMP), dt);
+        self.shoot.update(frame.held(ActionFrame::SHOOT), dt);
+        self.dash.update(frame.held(ActionFrame::DASH), dt);
+        self.aim_up
+            .update(stick.y >= PLAYER_CONFIG.aim_threshold, dt);
+        self.aim_down
+            .update(stick.y <= -PLAYER_CONFIG.aim_threshold, dt);
+    }
 }
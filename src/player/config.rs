@@ -28,6 +28,15 @@ pub struct PlayerConfig {
     pub permanent_invincibility: bool,
     pub hearts_offset: Vec2,
     pub heart_spacing: f32,
+    pub stick_deadzone: f32,
+    pub aim_threshold: f32,
+    pub dash_speed: f32,
+    pub dash_duration: f32,
+    pub dash_cooldown: f32,
+    pub dash_invincibility: f32,
+    pub dash_afterimage_interval: f32,
+    pub dash_afterimage_lifetime: f32,
+    pub dash_afterimage_alpha: f32,
 }
 
 pub const PLAYER_CONFIG: PlayerConfig = PlayerConfig {
@@ -55,4 +64,13 @@ pub const PLAYER_CONFIG: PlayerConfig = PlayerConfig {
     permanent_invincibility: false,
     hearts_offset: Vec2::new(10.0, 10.0),
     heart_spacing: 10.0,
+    stick_deadzone: 0.25,
+    aim_threshold: 0.5,
+    dash_speed: 520.0,
+    dash_duration: 0.18,
+    dash_cooldown: 0.6,
+    dash_invincibility: 0.22,
+    dash_afterimage_interval: 0.03,
+    dash_afterimage_lifetime: 0.2,
+    dash_afterimage_alpha: 0.5,
 };
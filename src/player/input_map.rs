@@ -0,0 +1,156 @@
+use crate::storage;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const INPUT_MAP_STORAGE_KEY: &str = "input_map";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum InputAction {
+    MoveLeft,
+    MoveRight,
+    AimUp,
+    AimDown,
+    Jump,
+    Shoot,
+    Dash,
+}
+
+impl InputAction {
+    pub const ALL: [InputAction; 7] = [
+        InputAction::MoveLeft,
+        InputAction::MoveRight,
+        InputAction::AimUp,
+        InputAction::AimDown,
+        InputAction::Jump,
+        InputAction::Shoot,
+        InputAction::Dash,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            InputAction::MoveLeft => "Move left",
+            InputAction::MoveRight => "Move right",
+            InputAction::AimUp => "Aim up",
+            InputAction::AimDown => "Aim down",
+            InputAction::Jump => "Jump",
+            InputAction::Shoot => "Shoot",
+            InputAction::Dash => "Dash",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Button(GamepadButton),
+}
+
+impl InputBinding {
+    pub fn label(self) -> String {
+        match self {
+            InputBinding::Key(key) => format!("{key:?}"),
+            InputBinding::Button(button) => format!("Pad {button:?}"),
+        }
+    }
+}
+
+/// Keys and gamepad buttons bound to each action. The left stick always
+/// moves and aims on top of these.
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InputMap {
+    bindings: BTreeMap<InputAction, Vec<InputBinding>>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        use InputBinding::{Button, Key};
+
+        let bindings = InputAction::ALL
+            .into_iter()
+            .map(|action| {
+                let defaults = match action {
+                    InputAction::MoveLeft => {
+                        vec![Key(KeyCode::ArrowLeft), Button(GamepadButton::DPadLeft)]
+                    }
+                    InputAction::MoveRight => {
+                        vec![Key(KeyCode::ArrowRight), Button(GamepadButton::DPadRight)]
+                    }
+                    InputAction::AimUp => {
+                        vec![Key(KeyCode::ArrowUp), Button(GamepadButton::DPadUp)]
+                    }
+                    InputAction::AimDown => {
+                        vec![Key(KeyCode::ArrowDown), Button(GamepadButton::DPadDown)]
+                    }
+                    InputAction::Jump => vec![Key(KeyCode::Space), Button(GamepadButton::South)],
+                    InputAction::Shoot => vec![
+                        Key(KeyCode::ControlLeft),
+                        Key(KeyCode::ControlRight),
+                        Key(KeyCode::ShiftLeft),
+                        Key(KeyCode::ShiftRight),
+                        Button(GamepadButton::West),
+                        Button(GamepadButton::RightTrigger2),
+                    ],
+                    InputAction::Dash => vec![Key(KeyCode::KeyX), Button(GamepadButton::East)],
+                };
+                (action, defaults)
+            })
+            .collect();
+        Self { bindings }
+    }
+}
+
+impl InputMap {
+    /// The stored map, or the defaults if none was saved or it can't be read.
+    /// Actions missing from an older save keep their default bindings.
+    pub fn load() -> Self {
+        let mut map = Self::default();
+        let Some(text) = storage::load(INPUT_MAP_STORAGE_KEY) else {
+            return map;
+        };
+        match ron::from_str::<InputMap>(&text) {
+            Ok(saved) => map.bindings.extend(saved.bindings),
+            Err(err) => warn!("ignoring saved input map: {err}"),
+        }
+        map
+    }
+
+    pub fn save(&self) {
+        let result = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| err.to_string())
+            .and_then(|text| {
+                storage::save(INPUT_MAP_STORAGE_KEY, &text).map_err(|err| err.to_string())
+            });
+        if let Err(err) = result {
+            error!("could not save input map: {err}");
+        }
+    }
+
+    pub fn bindings(&self, action: InputAction) -> &[InputBinding] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Binds `binding` to `action`, taking it away from any other action.
+    pub fn bind(&mut self, action: InputAction, binding: InputBinding) {
+        for bindings in self.bindings.values_mut() {
+            bindings.retain(|existing| *existing != binding);
+        }
+        self.bindings.entry(action).or_default().push(binding);
+    }
+
+    pub fn clear(&mut self, action: InputAction) {
+        self.bindings.entry(action).or_default().clear();
+    }
+
+    pub fn held(
+        &self,
+        action: InputAction,
+        keyboard: &ButtonInput<KeyCode>,
+        gamepads: &Query<&Gamepad>,
+    ) -> bool {
+        self.bindings(action).iter().any(|binding| match *binding {
+            InputBinding::Key(key) => keyboard.pressed(key),
+            InputBinding::Button(button) => gamepads.iter().any(|pad| pad.pressed(button)),
+        })
+    }
+}
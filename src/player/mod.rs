@@ -1,9 +1,11 @@
 pub mod camera_follow_system;
 pub mod components;
 pub mod config;
+pub mod input_map;
 pub mod player_collider_resize_system;
 pub mod player_collision_system;
 pub mod player_damage_system;
+pub mod player_dash_system;
 pub mod player_gravity_system;
 pub mod player_input_system;
 pub mod player_jump_anim_system;
@@ -14,6 +16,8 @@ pub mod player_sprite_flip_system;
 pub mod player_sprite_offset_system;
 pub mod player_ui_system;
 pub mod player_win_pose_system;
+pub mod plugin;
+pub mod rebind_screen;
 pub mod setup_player;
 pub mod track_player_position_system;
 
@@ -27,6 +31,9 @@ pub use player_damage_system::{
     player_invincibility_blink_system, player_invincibility_system, player_prone_system,
     player_respawn_system,
 };
+pub use player_dash_system::{
+    dash_afterimage_fade_system, player_dash_anim_system, player_dash_system,
+};
 pub use player_gravity_system::player_gravity_system;
 pub use player_input_system::player_input_system;
 pub use player_movement_system::player_movement_system;
@@ -34,5 +41,7 @@ pub use player_shooting_system::player_shooting_system;
 pub use player_sprite_offset_system::player_sprite_offset_system;
 pub use player_ui_system::{player_hearts_update_system, setup_player_hearts_ui};
 pub use player_win_pose_system::player_win_pose_system;
+pub use plugin::PlayerPlugin;
+pub use rebind_screen::RebindScreenPlugin;
 pub use setup_player::setup_player;
 pub use track_player_position_system::track_player_position_system;
@@ -3,12 +3,16 @@ use super::components::{
     PlayerInvincibility, PlayerLives, PlayerProne, PlayerRespawning, PlayerSpawnPoint,
     PlayerSprite, PlayerSpriteEntity, PlayerSpriteKind, Velocity,
 };
+use crate::app_state::RunFinished;
 use crate::assets::GameAssets;
 use crate::audio::{SfxEmitters, play_sfx_once};
+use crate::bridge::send_run_result;
 use crate::boss::components::{Boss, BossCollider, BossStage, BossStageKind};
+use crate::checkpoint::Checkpoints;
 use crate::components::MainCamera;
+use crate::soundtrack::{Stinger, play_stinger};
 use crate::systems::PlayerControl;
-use crate::constants::{PROJECTILE_SIZE, SCREEN_WIDTH};
+use crate::constants::SCREEN_WIDTH;
 use crate::enemy_a::robot_components::{
     ENEMY_ROBOT_HEIGHT, ENEMY_ROBOT_WIDTH, EnemyRobot, EnemyRobotState, EnemyRobotStateKind,
     EnemySpawnProtection,
@@ -46,7 +50,7 @@ pub fn player_enemy_projectile_hit_system(
         With<Player>,
     >,
     projectile_query: Query<
-        (Entity, &Transform, &Projectile, Option<&Sprite>),
+        (Entity, &Transform, &Projectile),
         (With<Projectile>, With<EnemyProjectile>),
     >,
     mut damage_writer: EventWriter<PlayerDamagedEvent>,
@@ -72,20 +76,14 @@ pub fn player_enemy_projectile_hit_system(
     );
     let player_size = Vec2::new(sprite_size.width, sprite_size.height);
 
-    for (projectile_entity, projectile_transform, projectile, sprite_opt) in projectile_query.iter()
-    {
-        let projectile_size = sprite_opt
-            .and_then(|sprite| sprite.custom_size)
-            .map(|size| Vec2::new(size.x, size.y))
-            .unwrap_or(Vec2::splat(PROJECTILE_SIZE));
-
+    for (projectile_entity, projectile_transform, projectile) in projectile_query.iter() {
         if let Some(hit_center) = swept_projectile_hit_center(
             projectile.previous_translation,
             Vec2::new(
                 projectile_transform.translation.x,
                 projectile_transform.translation.y,
             ),
-            projectile_size,
+            projectile.size,
             player_min,
             player_size,
         ) {
@@ -660,9 +658,8 @@ pub fn player_game_over_system(
     mut commands: Commands,
     time: Res<Time>,
     mut query: Query<(Entity, &mut PlayerGameOver, Option<&PlayerProne>)>,
-    game_assets: Res<GameAssets>,
-    emitters: Option<Res<SfxEmitters>>,
     mut control: Option<ResMut<PlayerControl>>,
+    checkpoints: Res<Checkpoints>,
 ) {
     let dt = time.delta_secs();
     if dt <= 0.0 {
@@ -686,13 +683,7 @@ pub fn player_game_over_system(
         if !game_over.sfx_played {
             game_over.sfx_delay -= dt;
             if game_over.sfx_delay <= 0.0 {
-                if let Some(emitters) = emitters.as_deref() {
-                    play_sfx_once(
-                        &mut commands,
-                        emitters.player_game_over,
-                        game_assets.player_game_over_sfx.clone(),
-                    );
-                }
+                play_stinger(&mut commands, Stinger::GameOver);
                 game_over.sfx_played = true;
             }
         }
@@ -706,14 +697,15 @@ pub fn player_game_over_system(
             continue;
         }
 
-        game_over.result_sent = true;
-
-        #[cfg(target_arch = "wasm32")]
-        {
-            crate::systems::browser_events::send_game_result(false);
+        // The continue prompt decides what happens next.
+        if checkpoints.snapshot.is_some() && !game_over.continue_declined {
+            continue;
         }
 
-        commands.trigger(bevy::app::AppExit::Success);
+        game_over.result_sent = true;
+
+        commands.queue(|world: &mut World| send_run_result(world, false));
+        commands.send_event(RunFinished { win: false });
         break;
     }
 }
@@ -0,0 +1,147 @@
+use super::components::{
+    DashAfterimage, Player, PlayerActions, PlayerDash, PlayerDirection, PlayerInvincibility,
+    PlayerProne, PlayerRespawning, PlayerSpriteEntity, PlayerSpriteKind, Velocity,
+};
+use super::config::PLAYER_CONFIG;
+use crate::assets::GameAssets;
+use bevy::prelude::*;
+
+type KnockedDown = Or<(With<PlayerProne>, With<PlayerRespawning>)>;
+type CanDash = (
+    With<Player>,
+    Without<PlayerProne>,
+    Without<PlayerRespawning>,
+);
+
+pub fn player_dash_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    actions: Res<PlayerActions>,
+    mut player_query: Query<
+        (
+            Entity,
+            &mut PlayerDash,
+            &mut Velocity,
+            &mut PlayerDirection,
+            Option<&PlayerInvincibility>,
+        ),
+        CanDash,
+    >,
+    mut downed_query: Query<&mut PlayerDash, KnockedDown>,
+) {
+    let dt = time.delta_secs();
+
+    // Getting knocked down cancels the dash so it can't override the knockback.
+    for mut dash in downed_query.iter_mut() {
+        dash.timer = 0.0;
+    }
+
+    for (entity, mut dash, mut velocity, mut direction, invincibility) in player_query.iter_mut() {
+        dash.cooldown = (dash.cooldown - dt).max(0.0);
+
+        if dash.is_dashing() {
+            dash.timer -= dt;
+            velocity.x = if dash.is_dashing() {
+                dash.direction * PLAYER_CONFIG.dash_speed
+            } else {
+                dash.direction * PLAYER_CONFIG.max_ground_speed
+            };
+            velocity.y = 0.0;
+            continue;
+        }
+
+        if !actions.dash.just_pressed || dash.cooldown > 0.0 {
+            continue;
+        }
+
+        dash.direction = if actions.move_axis != 0.0 {
+            actions.move_axis.signum()
+        } else if direction.facing_right {
+            1.0
+        } else {
+            -1.0
+        };
+        dash.timer = PLAYER_CONFIG.dash_duration;
+        dash.cooldown = PLAYER_CONFIG.dash_cooldown;
+        dash.afterimage_timer = 0.0;
+        direction.facing_right = dash.direction > 0.0;
+        direction.last_movement_direction = dash.direction;
+        velocity.x = dash.direction * PLAYER_CONFIG.dash_speed;
+        velocity.y = 0.0;
+
+        // Don't cut short a longer respawn invincibility.
+        if invincibility.is_none_or(|inv| inv.timer < PLAYER_CONFIG.dash_invincibility) {
+            commands.entity(entity).insert(PlayerInvincibility {
+                timer: PLAYER_CONFIG.dash_invincibility,
+            });
+        }
+    }
+}
+
+/// Holds the dash pose and leaves a fading trail of copies of the sprite.
+pub fn player_dash_anim_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    game_assets: Res<GameAssets>,
+    mut player_query: Query<(&Transform, &mut PlayerDash, &PlayerSpriteEntity), With<Player>>,
+    mut sprite_query: Query<(&mut Sprite, &mut Transform, &mut PlayerSpriteKind), Without<Player>>,
+) {
+    for (player_transform, mut dash, sprite_entity) in player_query.iter_mut() {
+        if !dash.is_dashing() {
+            continue;
+        }
+        let Ok((mut sprite, mut sprite_transform, mut kind)) =
+            sprite_query.get_mut(**sprite_entity)
+        else {
+            continue;
+        };
+        if *kind == PlayerSpriteKind::Win {
+            continue;
+        }
+
+        if sprite.image != game_assets.player_run_c {
+            sprite.image = game_assets.player_run_c.clone();
+        }
+        sprite_transform.rotation = Quat::IDENTITY;
+        *kind = PlayerSpriteKind::Dash;
+
+        dash.afterimage_timer -= time.delta_secs();
+        if dash.afterimage_timer > 0.0 {
+            continue;
+        }
+        dash.afterimage_timer += PLAYER_CONFIG.dash_afterimage_interval;
+
+        let mut translation = player_transform.translation + sprite_transform.translation;
+        translation.z -= 0.05;
+        commands.spawn((
+            Sprite {
+                image: sprite.image.clone(),
+                flip_x: dash.direction < 0.0,
+                color: Color::WHITE.with_alpha(PLAYER_CONFIG.dash_afterimage_alpha),
+                ..default()
+            },
+            Transform::from_translation(translation),
+            DashAfterimage {
+                timer: PLAYER_CONFIG.dash_afterimage_lifetime,
+            },
+        ));
+    }
+}
+
+pub fn dash_afterimage_fade_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut DashAfterimage, &mut Sprite)>,
+) {
+    for (entity, mut afterimage, mut sprite) in query.iter_mut() {
+        afterimage.timer -= time.delta_secs();
+        if afterimage.timer <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let fraction = afterimage.timer / PLAYER_CONFIG.dash_afterimage_lifetime;
+        sprite.color = sprite
+            .color
+            .with_alpha(PLAYER_CONFIG.dash_afterimage_alpha * fraction);
+    }
+}
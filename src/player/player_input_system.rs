@@ -1,4 +1,5 @@
 use super::components::*;
+use super::input_map::{InputAction, InputMap};
 use crate::player::PLAYER_CONFIG;
 use crate::systems::PlayerControl;
 use bevy::input::ButtonInput;
@@ -8,7 +9,9 @@ use bevy::prelude::*;
 pub fn gather_player_actions(
     time: Res<Time>,
     control: Option<Res<PlayerControl>>,
+    input_map: Res<InputMap>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
     mut actions: ResMut<PlayerActions>,
 ) {
     if control.is_some_and(|ctrl| !ctrl.enabled) {
@@ -16,51 +19,43 @@ pub fn gather_player_actions(
         return;
     }
 
-    let dt = time.delta_secs();
+    let held = |action| input_map.held(action, &keyboard_input, &gamepads);
 
-    let mut movement: f32 = 0.0;
-    if keyboard_input.pressed(KeyCode::ArrowLeft) {
-        movement -= 1.0;
-    }
-    if keyboard_input.pressed(KeyCode::ArrowRight) {
-        movement += 1.0;
+    let mut direction = Vec2::ZERO;
+    if held(InputAction::MoveLeft) {
+        direction.x -= 1.0;
     }
-    actions.move_axis = movement.clamp(-1.0, 1.0);
-
-    let mut aim_axis = Vec2::ZERO;
-    if keyboard_input.pressed(KeyCode::ArrowLeft) {
-        aim_axis.x -= 1.0;
+    if held(InputAction::MoveRight) {
+        direction.x += 1.0;
     }
-    if keyboard_input.pressed(KeyCode::ArrowRight) {
-        aim_axis.x += 1.0;
+    if held(InputAction::AimUp) {
+        direction.y += 1.0;
     }
-    if keyboard_input.pressed(KeyCode::ArrowUp) {
-        aim_axis.y += 1.0;
+    if held(InputAction::AimDown) {
+        direction.y -= 1.0;
     }
-    if keyboard_input.pressed(KeyCode::ArrowDown) {
-        aim_axis.y -= 1.0;
+
+    // Every connected pad is read, so plugging one in mid-run just works.
+    for gamepad in gamepads.iter() {
+        direction += apply_deadzone(gamepad.left_stick(), PLAYER_CONFIG.stick_deadzone);
     }
-    if aim_axis.length_squared() > 1.0 {
-        aim_axis = aim_axis.normalize();
+
+    let mut frame = ActionFrame::default();
+    frame.set_stick(direction);
+    frame.set(ActionFrame::JUMP, held(InputAction::Jump));
+    frame.set(ActionFrame::SHOOT, held(InputAction::Shoot));
+    frame.set(ActionFrame::DASH, held(InputAction::Dash));
+
+    actions.apply_frame(frame, time.delta_secs());
+}
+
+fn apply_deadzone(stick: Vec2, deadzone: f32) -> Vec2 {
+    let length = stick.length();
+    if length <= deadzone {
+        return Vec2::ZERO;
     }
-    actions.aim_axis = aim_axis;
-
-    let shoot_pressed = keyboard_input.pressed(KeyCode::ControlLeft)
-        || keyboard_input.pressed(KeyCode::ControlRight)
-        || keyboard_input.pressed(KeyCode::ShiftLeft)
-        || keyboard_input.pressed(KeyCode::ShiftRight);
-
-    actions
-        .jump
-        .update(keyboard_input.pressed(KeyCode::Space), dt);
-    actions.shoot.update(shoot_pressed, dt);
-    actions.dash.update(false, dt);
-    actions
-        .aim_up
-        .update(keyboard_input.pressed(KeyCode::ArrowUp), dt);
-    actions
-        .aim_down
-        .update(keyboard_input.pressed(KeyCode::ArrowDown), dt);
+    let scaled = ((length - deadzone) / (1.0 - deadzone)).min(1.0);
+    stick / length * scaled
 }
 
 pub fn player_input_system(
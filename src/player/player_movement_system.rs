@@ -1,10 +1,11 @@
 use super::components::*;
-use crate::components::{CameraState, MainCamera};
-use crate::constants::{SCREEN_WIDTH, WORLD_WIDTH};
+use crate::components::{CameraState, MainCamera, WorldBounds};
+use crate::constants::SCREEN_WIDTH;
 use bevy::prelude::*;
 
 pub fn player_movement_system(
     time: Res<Time>,
+    world: Res<WorldBounds>,
     camera_query: Query<&CameraState, With<MainCamera>>,
     mut player_query: Query<
         (
@@ -23,7 +24,7 @@ pub fn player_movement_system(
             let half_screen = SCREEN_WIDTH / 2.0;
             let clamped_max = state
                 .max_reached_x
-                .clamp(half_screen, WORLD_WIDTH - half_screen);
+                .clamp(half_screen, world.width - half_screen);
             clamped_max - half_screen
         })
         .unwrap_or(0.0);
@@ -40,7 +41,7 @@ pub fn player_movement_system(
         let world_min_x = sprite_size.width / 2.0;
         let camera_min_x = camera_left_edge + sprite_size.width / 2.0;
         let min_x = camera_min_x.max(world_min_x);
-        let max_x = WORLD_WIDTH - sprite_size.width / 2.0;
+        let max_x = world.width - sprite_size.width / 2.0;
         transform.translation.x = transform.translation.x.clamp(min_x, max_x);
     }
 }
@@ -4,6 +4,7 @@ use super::components::{
 };
 use crate::assets::GameAssets;
 use crate::projectile::spawn_projectile;
+use crate::weapon::Weapon;
 use bevy::prelude::*;
 
 pub fn player_shooting_system(
@@ -19,6 +20,7 @@ pub fn player_shooting_system(
             &mut PlayerShootingAnim,
             &mut PlayerRunAnim,
             &PlayerSpriteEntity,
+            &Weapon,
             Option<&PlayerProne>,
             Option<&PlayerRespawning>,
         ),
@@ -37,6 +39,7 @@ pub fn player_shooting_system(
         mut shooting_anim,
         run_anim,
         sprite_entity,
+        weapon,
         prone,
         respawning,
     ) in player_query.iter_mut()
@@ -167,7 +170,13 @@ pub fn player_shooting_system(
                         let gun_y = top_left_y - 24.0;
                         spawn_pos = Vec3::new(gun_x, gun_y, player_transform.translation.z + 0.1);
                     }
-                    spawn_projectile(&mut commands, spawn_pos, direction);
+                    spawn_projectile(
+                        &mut commands,
+                        spawn_pos,
+                        direction,
+                        weapon.kind.stats(),
+                        game_assets.weapon_projectile(weapon.kind),
+                    );
                     shooting_state.last_shot_timer = 0.0;
                     shooting_anim.frame = 1;
                     shooting_anim.timer = shooting_state.shot_cooldown;
@@ -13,6 +13,7 @@ fn offset_for_kind(kind: &PlayerSpriteKind) -> Vec2 {
         PlayerSpriteKind::Hit => Vec2::new(0.0, 0.0),
         PlayerSpriteKind::Fallen => Vec2::new(0.0, -25.0),
         PlayerSpriteKind::Win => Vec2::new(0.0, 0.0),
+        PlayerSpriteKind::Dash => Vec2::new(0.0, 0.0),
     }
 }
 
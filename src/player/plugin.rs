@@ -0,0 +1,120 @@
+use super::components::PlayerActions;
+use super::player_input_system::gather_player_actions;
+use super::player_jump_anim_system::player_jump_anim_system;
+use super::player_run_anim_system::player_run_anim_system;
+use super::player_sprite_flip_system::player_sprite_flip_system;
+use super::track_player_position_system::PositionTriggerState;
+use super::*;
+use crate::assets::load_game_assets;
+use crate::enemy_a::enemy_robot_behavior_system;
+use crate::enemy_b::enemy_b_behavior_system;
+use crate::enemy_c::enemy_c_movement_system;
+use crate::miniboss::miniboss_movement_system;
+use crate::projectile::projectile_movement_system;
+use crate::replay::InputPlayback;
+use crate::systems::setup_camera;
+use bevy::prelude::*;
+
+pub struct PlayerPlugin;
+
+impl Plugin for PlayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerActions>()
+            .init_resource::<PositionTriggerState>()
+            .add_plugins(RebindScreenPlugin)
+            .add_event::<PlayerDamagedEvent>()
+            .add_systems(
+                Startup,
+                (
+                    setup_player.after(load_game_assets),
+                    setup_player_hearts_ui
+                        .after(setup_camera)
+                        .after(load_game_assets),
+                ),
+            )
+            .add_systems(
+                FixedPreUpdate,
+                gather_player_actions.run_if(not(resource_exists::<InputPlayback>)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    player_gravity_system,
+                    player_input_system,
+                    player_movement_system,
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                player_dash_system
+                    .after(player_gravity_system)
+                    .after(player_input_system)
+                    .before(player_movement_system),
+            )
+            .add_systems(
+                FixedUpdate,
+                track_player_position_system.after(player_movement_system),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    player_collider_resize_system.after(player_movement_system),
+                    player_collision_system.after(player_collider_resize_system),
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    player_enemy_projectile_hit_system.after(projectile_movement_system),
+                    player_enemy_contact_damage_system
+                        .after(player_enemy_projectile_hit_system)
+                        .after(enemy_robot_behavior_system)
+                        .after(enemy_b_behavior_system)
+                        .after(enemy_c_movement_system)
+                        .after(miniboss_movement_system),
+                    player_damage_system.after(player_enemy_contact_damage_system),
+                    player_prone_system.after(player_damage_system),
+                    player_game_over_system.after(player_prone_system),
+                    player_invincibility_system.after(player_damage_system),
+                    player_respawn_system.after(player_damage_system),
+                    player_invincibility_blink_system.after(player_invincibility_system),
+                    player_hearts_update_system.after(player_prone_system),
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    camera_follow_system,
+                    player_shooting_system,
+                    player_jump_anim_system,
+                    player_run_anim_system,
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    player_dash_anim_system
+                        .after(player_dash_system)
+                        .after(player_run_anim_system)
+                        .after(player_shooting_system)
+                        .after(player_jump_anim_system),
+                    dash_afterimage_fade_system,
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                player_win_pose_system
+                    .after(player_run_anim_system)
+                    .after(player_shooting_system)
+                    .after(player_jump_anim_system)
+                    .after(player_dash_anim_system),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    player_sprite_flip_system.after(player_win_pose_system),
+                    player_sprite_offset_system.after(player_win_pose_system),
+                ),
+            );
+    }
+}
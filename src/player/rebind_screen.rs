@@ -0,0 +1,303 @@
+use super::input_map::{InputAction, InputBinding, InputMap};
+use bevy::prelude::*;
+
+/// Opened with F1 or the gamepad Select button. Gameplay is frozen while it
+/// is up and the map is saved when it closes.
+#[derive(Resource, Default)]
+pub struct RebindScreen {
+    pub open: bool,
+    selected: usize,
+    capturing: bool,
+    /// Set once the frame capture started on is over, so the key that
+    /// started it isn't bound.
+    capture_armed: bool,
+    open_requested: bool,
+    /// Whether opening the screen is what paused the game.
+    paused_time: bool,
+}
+
+impl RebindScreen {
+    /// Opens the screen on the next frame, e.g. from the pause menu.
+    pub fn request_open(&mut self) {
+        if !self.open {
+            self.open_requested = true;
+        }
+    }
+}
+
+#[derive(Component)]
+struct RebindScreenRoot;
+
+#[derive(Component)]
+struct RebindRow(usize);
+
+#[derive(Component)]
+struct RebindHint;
+
+const ROW_COLOR: Color = Color::srgb(0.7, 0.7, 0.7);
+const SELECTED_ROW_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+pub struct RebindScreenPlugin;
+
+impl Plugin for RebindScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(InputMap::load())
+            .init_resource::<RebindScreen>()
+            .add_systems(
+                Update,
+                (
+                    toggle_rebind_screen,
+                    rebind_navigation_system.run_if(rebind_screen_open),
+                    rebind_capture_system.run_if(rebind_screen_open),
+                    rebind_screen_text_system.run_if(rebind_screen_open),
+                )
+                    .chain(),
+            );
+    }
+}
+
+fn rebind_screen_open(screen: Res<RebindScreen>) -> bool {
+    screen.open
+}
+
+fn toggle_rebind_screen(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut screen: ResMut<RebindScreen>,
+    mut time: ResMut<Time<Virtual>>,
+    input_map: Res<InputMap>,
+    roots: Query<Entity, With<RebindScreenRoot>>,
+) {
+    let toggled = keyboard.just_pressed(KeyCode::F1)
+        || gamepads
+            .iter()
+            .any(|pad| pad.just_pressed(GamepadButton::Select))
+        || screen.open_requested;
+    if !toggled || screen.capturing {
+        return;
+    }
+
+    if screen.open {
+        for root in roots.iter() {
+            commands.entity(root).despawn();
+        }
+        // Leave the game paused if the pause menu was already holding it.
+        if screen.paused_time {
+            time.unpause();
+        }
+        *screen = RebindScreen::default();
+        input_map.save();
+        return;
+    }
+
+    screen.open = true;
+    screen.open_requested = false;
+    screen.paused_time = !time.is_paused();
+    time.pause();
+    spawn_rebind_screen(&mut commands);
+}
+
+fn spawn_rebind_screen(commands: &mut Commands) {
+    commands
+        .spawn((
+            RebindScreenRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            GlobalZIndex(10),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("CONTROLS"),
+                TextFont::from_font_size(28.0),
+                Node {
+                    margin: UiRect::bottom(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+            for index in 0..InputAction::ALL.len() {
+                root.spawn((
+                    RebindRow(index),
+                    Text::default(),
+                    TextFont::from_font_size(18.0),
+                    TextColor(ROW_COLOR),
+                ));
+            }
+            root.spawn((
+                RebindHint,
+                Text::default(),
+                TextFont::from_font_size(14.0),
+                TextColor(ROW_COLOR),
+                Node {
+                    margin: UiRect::top(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn rebind_navigation_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut screen: ResMut<RebindScreen>,
+    mut input_map: ResMut<InputMap>,
+) {
+    if screen.capturing {
+        return;
+    }
+
+    let pressed = |key: KeyCode, button: GamepadButton| {
+        keyboard.just_pressed(key) || gamepads.iter().any(|pad| pad.just_pressed(button))
+    };
+
+    let count = InputAction::ALL.len();
+    if pressed(KeyCode::ArrowUp, GamepadButton::DPadUp) {
+        screen.selected = (screen.selected + count - 1) % count;
+    }
+    if pressed(KeyCode::ArrowDown, GamepadButton::DPadDown) {
+        screen.selected = (screen.selected + 1) % count;
+    }
+
+    let action = InputAction::ALL[screen.selected];
+    if pressed(KeyCode::Enter, GamepadButton::South) {
+        screen.capturing = true;
+        screen.capture_armed = false;
+    } else if pressed(KeyCode::Backspace, GamepadButton::North) {
+        input_map.clear(action);
+    } else if pressed(KeyCode::Delete, GamepadButton::West) {
+        *input_map = InputMap::default();
+    }
+}
+
+/// Binds the next key or button pressed to the selected action. Escape
+/// cancels; it, the screen toggles and Start (pause) can't be bound.
+fn rebind_capture_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut screen: ResMut<RebindScreen>,
+    mut input_map: ResMut<InputMap>,
+) {
+    if !screen.capturing {
+        return;
+    }
+    // Skip the frame capture started on, the confirm press is still "just pressed".
+    if !screen.capture_armed {
+        screen.capture_armed = true;
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::Escape) {
+        screen.capturing = false;
+        return;
+    }
+
+    let binding = keyboard
+        .get_just_pressed()
+        .find(|key| **key != KeyCode::F1)
+        .map(|key| InputBinding::Key(*key))
+        .or_else(|| {
+            gamepads
+                .iter()
+                .find_map(|pad| {
+                    pad.get_just_pressed()
+                        .find(|button| {
+                            !matches!(button, GamepadButton::Select | GamepadButton::Start)
+                        })
+                        .copied()
+                })
+                .map(InputBinding::Button)
+        });
+
+    if let Some(binding) = binding {
+        input_map.bind(InputAction::ALL[screen.selected], binding);
+        screen.capturing = false;
+    }
+}
+
+fn rebind_screen_text_system(
+    screen: Res<RebindScreen>,
+    input_map: Res<InputMap>,
+    mut rows: Query<(&RebindRow, &mut Text, &mut TextColor), Without<RebindHint>>,
+    mut hint: Query<&mut Text, With<RebindHint>>,
+) {
+    if !screen.is_changed() && !input_map.is_changed() {
+        return;
+    }
+
+    for (row, mut text, mut color) in rows.iter_mut() {
+        let action = InputAction::ALL[row.0];
+        let selected = row.0 == screen.selected;
+        let bindings = if selected && screen.capturing {
+            "press a key or button...".to_string()
+        } else {
+            let labels: Vec<String> = input_map
+                .bindings(action)
+                .iter()
+                .map(|binding| binding.label())
+                .collect();
+            if labels.is_empty() {
+                "-".to_string()
+            } else {
+                labels.join(", ")
+            }
+        };
+        text.0 = format!("{}: {bindings}", action.label());
+        color.0 = if selected {
+            SELECTED_ROW_COLOR
+        } else {
+            ROW_COLOR
+        };
+    }
+
+    for mut text in hint.iter_mut() {
+        text.0 = if screen.capturing {
+            "Esc: cancel".to_string()
+        } else {
+            "Enter/A: add binding   Backspace/Y: clear   Del/X: defaults   F1/Select: close"
+                .to_string()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(app: &mut App, key: KeyCode) {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release_all();
+        keyboard.clear();
+        keyboard.press(key);
+        app.update();
+    }
+
+    #[test]
+    fn binds_the_key_pressed_after_confirming() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<RebindScreen>()
+            .init_resource::<InputMap>()
+            .add_systems(
+                Update,
+                (rebind_navigation_system, rebind_capture_system).chain(),
+            );
+        let action = InputAction::ALL[0];
+
+        press(&mut app, KeyCode::Enter);
+        assert!(app.world().resource::<RebindScreen>().capturing);
+        press(&mut app, KeyCode::KeyK);
+
+        let bindings = app.world().resource::<InputMap>().bindings(action);
+        assert!(bindings.contains(&InputBinding::Key(KeyCode::KeyK)));
+        assert!(!bindings.contains(&InputBinding::Key(KeyCode::Enter)));
+        assert!(!app.world().resource::<RebindScreen>().capturing);
+    }
+}
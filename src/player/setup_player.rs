@@ -4,15 +4,19 @@ use crate::constants::{GROUND_RECT_HEIGHT, SCREEN_HEIGHT, SCREEN_WIDTH, Z_PLAYER
 use crate::player::PLAYER_CONFIG;
 use bevy::prelude::*;
 
-pub fn setup_player(mut commands: Commands, game_assets: Res<GameAssets>) {
+pub fn player_spawn_translation() -> Vec3 {
     let spawn_x = SCREEN_WIDTH * PLAYER_CONFIG.spawn_screen_fraction;
     let spawn_world_y = GROUND_RECT_HEIGHT + PLAYER_CONFIG.ground_collider.y / 2.0;
 
-    let translation = Vec3::new(
+    Vec3::new(
         spawn_x,
         spawn_world_y - (SCREEN_HEIGHT / 2.0),
         Z_PLAYER_BASE,
-    );
+    )
+}
+
+pub fn setup_player(mut commands: Commands, game_assets: Res<GameAssets>) {
+    let translation = player_spawn_translation();
 
     let sprite_size = SpriteSize {
         width: PLAYER_CONFIG.ground_collider.x,
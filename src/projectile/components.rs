@@ -1,10 +1,16 @@
+use crate::components::Damage;
+use crate::interpolation::TransformInterpolation;
 use bevy::prelude::*;
 
 #[derive(Component)]
+#[require(TransformInterpolation)]
 pub struct Projectile {
     pub direction: Vec2,
     pub speed: f32,
     pub previous_translation: Vec2,
+    pub damage: Damage,
+    /// Axis-aligned hitbox, fixed when the projectile spawns.
+    pub size: Vec2,
 }
 
 #[derive(Component)]
@@ -1,11 +1,10 @@
 pub mod components;
+pub mod plugin;
 pub mod projectile_fx_systems;
 pub mod projectile_movement_system;
 pub mod projectile_spawning_system;
 
-pub use projectile_fx_systems::{
-    projectile_hit_fx_system, projectile_shoot_fx_flash_system,
-    projectile_shoot_fx_projectile_system,
-};
+pub use plugin::ProjectilePlugin;
+pub use projectile_fx_systems::{projectile_hit_fx_system, projectile_shoot_fx_flash_system};
 pub use projectile_movement_system::projectile_movement_system;
 pub use projectile_spawning_system::spawn_projectile;
@@ -0,0 +1,34 @@
+use super::components::ProjectileHitEvent;
+use super::projectile_fx_systems::projectile_hit_anim_update_system;
+use super::projectile_spawning_system::one_shot_lifetime_system;
+use super::*;
+use crate::enemy_a::enemy_robot_hit_system;
+use crate::enemy_c::enemy_c_hit_system;
+use crate::miniboss::miniboss_hit_system;
+use crate::player::player_shooting_system;
+use bevy::prelude::*;
+
+pub struct ProjectilePlugin;
+
+impl Plugin for ProjectilePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ProjectileHitEvent>()
+            .add_systems(FixedUpdate, projectile_movement_system)
+            .add_systems(
+                FixedUpdate,
+                projectile_shoot_fx_flash_system.after(player_shooting_system),
+            )
+            .add_systems(
+                FixedUpdate,
+                projectile_hit_fx_system
+                    .after(projectile_movement_system)
+                    .after(enemy_robot_hit_system)
+                    .after(enemy_c_hit_system)
+                    .after(miniboss_hit_system),
+            )
+            .add_systems(
+                FixedUpdate,
+                (projectile_hit_anim_update_system, one_shot_lifetime_system),
+            );
+    }
+}
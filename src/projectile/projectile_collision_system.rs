@@ -3,20 +3,16 @@ use crate::components::*;
 use crate::constants::SCREEN_HEIGHT;
 use crate::collision::rectangles_collide;
 use super::components::*;
-use crate::constants::PROJECTILE_SIZE;
 
 pub fn projectile_collision_system(
     mut commands: Commands,
-    projectile_query: Query<(Entity, &Transform, Option<&Sprite>), With<Projectile>>,
+    projectile_query: Query<(Entity, &Transform, &Projectile)>,
     geometry_query: Query<&LayerGeometry, With<Solid>>,
     mut hit_writer: EventWriter<ProjectileHitEvent>,
 ) {
-    for (projectile_entity, projectile_transform, sprite_opt) in projectile_query.iter() {
+    for (projectile_entity, projectile_transform, projectile) in projectile_query.iter() {
         let projectile_world_y = projectile_transform.translation.y + (SCREEN_HEIGHT / 2.0);
-        let (w, h) = sprite_opt
-            .and_then(|s| s.custom_size)
-            .map(|v| (v.x, v.y))
-            .unwrap_or((PROJECTILE_SIZE, PROJECTILE_SIZE));
+        let (w, h) = (projectile.size.x, projectile.size.y);
         let projectile_world_pos = Vec2::new(
             projectile_transform.translation.x - (w / 2.0),
             projectile_world_y - (h / 2.0)
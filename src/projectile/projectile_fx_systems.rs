@@ -7,25 +7,12 @@ const MUZZLE_FLASH_SIZE: Vec2 = Vec2::new(16.0, 16.0);
 const HIT_ANIM_Z_OFFSET: f32 = 0.02;
 const HIT_ANIM_FRAME_TIME: f32 = 0.01;
 
-pub fn projectile_shoot_fx_projectile_system(
+/// One flash and shot sound per trigger pull, however many projectiles the
+/// weapon fires.
+pub fn projectile_shoot_fx_flash_system(
     mut commands: Commands,
     assets: Res<GameAssets>,
     emitters: Res<SfxEmitters>,
-    mut q: Query<&mut Sprite, (With<PlayerProjectile>, Added<Projectile>)>,
-) {
-    for mut sprite in q.iter_mut() {
-        sprite.image = assets.player_projectile.clone();
-        sprite.custom_size = Some(Vec2::splat(crate::constants::PROJECTILE_SIZE));
-        play_sfx_once(
-            &mut commands,
-            emitters.player_shoot,
-            assets.shoot_sfx.clone(),
-        );
-    }
-}
-
-pub fn projectile_shoot_fx_flash_system(
-    assets: Res<GameAssets>,
     mut q: Query<&mut Sprite, (With<MuzzleFlash>, Added<MuzzleFlash>)>,
 ) {
     for mut sprite in q.iter_mut() {
@@ -33,6 +20,11 @@ pub fn projectile_shoot_fx_flash_system(
             sprite.image = assets.player_shoot_flash.clone();
         }
         sprite.custom_size = Some(MUZZLE_FLASH_SIZE);
+        play_sfx_once(
+            &mut commands,
+            emitters.player_shoot,
+            assets.shoot_sfx.clone(),
+        );
     }
 }
 
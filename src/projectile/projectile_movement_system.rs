@@ -1,15 +1,14 @@
 use super::components::*;
 use crate::collision::rectangles_collide;
-use crate::components::{LayerGeometry, Solid};
-use crate::constants::{
-    DESPAWN_MARGIN_X, DESPAWN_MARGIN_Y, PROJECTILE_SIZE, SCREEN_HEIGHT, WORLD_WIDTH,
-};
+use crate::components::{LayerGeometry, Solid, WorldBounds};
+use crate::constants::{DESPAWN_MARGIN_X, DESPAWN_MARGIN_Y, SCREEN_HEIGHT};
 use bevy::prelude::*;
 
 pub fn projectile_movement_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut projectile_query: Query<(Entity, &mut Transform, &mut Projectile, Option<&Sprite>)>,
+    world: Res<WorldBounds>,
+    mut projectile_query: Query<(Entity, &mut Transform, &mut Projectile)>,
     geometry_query: Query<&LayerGeometry, With<Solid>>,
     mut hit_writer: EventWriter<ProjectileHitEvent>,
 ) {
@@ -18,16 +17,13 @@ pub fn projectile_movement_system(
         return;
     }
 
-    for (entity, mut transform, mut projectile, sprite_opt) in projectile_query.iter_mut() {
+    for (entity, mut transform, mut projectile) in projectile_query.iter_mut() {
         let start_center = Vec2::new(transform.translation.x, transform.translation.y);
         let movement = projectile.direction * projectile.speed * delta;
 
         projectile.previous_translation = start_center;
 
-        let (width, height) = sprite_opt
-            .and_then(|sprite| sprite.custom_size)
-            .map(|size| (size.x, size.y))
-            .unwrap_or((PROJECTILE_SIZE, PROJECTILE_SIZE));
+        let (width, height) = (projectile.size.x, projectile.size.y);
 
         let step_distance = (width.min(height) * 0.5).max(1.0);
         let total_distance = movement.length();
@@ -87,7 +83,7 @@ pub fn projectile_movement_system(
         transform.translation.y = end_center.y;
 
         if transform.translation.x < -DESPAWN_MARGIN_X
-            || transform.translation.x > WORLD_WIDTH + DESPAWN_MARGIN_X
+            || transform.translation.x > world.width + DESPAWN_MARGIN_X
             || transform.translation.y < -DESPAWN_MARGIN_Y
             || transform.translation.y > SCREEN_HEIGHT + DESPAWN_MARGIN_Y
         {
@@ -1,26 +1,62 @@
 use super::components::*;
 use crate::assets::GameAssets;
+use crate::components::Damage;
 use crate::constants::{ENEMY_PROJECTILE_SPEED, PROJECTILE_SIZE, PROJECTILE_SPEED, Z_PROJECTILES};
+use crate::weapon::{HomingProjectile, WeaponStats};
 use bevy::prelude::*;
 
-pub fn spawn_projectile(commands: &mut Commands, position: Vec3, direction: Vec2) {
+/// Fires one shot of `weapon`: its projectiles fanned evenly across the
+/// weapon's spread around `direction`, plus a muzzle flash. `image` is the
+/// weapon's projectile sprite.
+pub fn spawn_projectile(
+    commands: &mut Commands,
+    position: Vec3,
+    direction: Vec2,
+    weapon: &WeaponStats,
+    image: &Handle<Image>,
+) {
     let mut pos = position;
     pos.z = pos.z.max(Z_PROJECTILES);
     let initial_translation = Vec2::new(pos.x, pos.y);
-    commands.spawn((
-        Sprite {
-            color: Color::WHITE,
-            custom_size: Some(Vec2::new(PROJECTILE_SIZE, PROJECTILE_SIZE)),
-            ..default()
-        },
-        Transform::from_translation(pos),
-        Projectile {
-            direction: direction.normalize(),
-            speed: PROJECTILE_SPEED,
-            previous_translation: initial_translation,
-        },
-        PlayerProjectile,
-    ));
+    let count = weapon.projectile_count.max(1);
+    let base_angle = direction.normalize().to_angle();
+    for index in 0..count {
+        let fan = if count == 1 {
+            0.0
+        } else {
+            index as f32 / (count - 1) as f32 - 0.5
+        };
+        let angle = base_angle + weapon.spread_degrees.to_radians() * fan;
+        let mut transform = Transform::from_translation(pos);
+        let mut hitbox = weapon.size;
+        // Square sprites stay upright, elongated ones point along the shot.
+        if weapon.size.x != weapon.size.y {
+            transform.rotation = Quat::from_rotation_z(angle);
+            hitbox = rotated_extent(weapon.size, angle);
+        }
+        let mut projectile = commands.spawn((
+            Sprite {
+                image: image.clone(),
+                custom_size: Some(weapon.size),
+                ..default()
+            },
+            transform,
+            Projectile {
+                direction: Vec2::from_angle(angle),
+                speed: weapon.speed,
+                previous_translation: initial_translation,
+                damage: Damage(weapon.damage),
+                size: hitbox,
+            },
+            PlayerProjectile,
+        ));
+        if let Some(turn_rate) = weapon.homing_turn_rate {
+            projectile.insert(HomingProjectile { turn_rate });
+        }
+        if let Some(lifetime) = weapon.lifetime {
+            projectile.insert(OneShotLifetime { timer: lifetime });
+        }
+    }
     commands.spawn((
         Sprite {
             color: Color::WHITE,
@@ -53,6 +89,8 @@ pub fn spawn_enemy_projectile(
             direction: direction.normalize(),
             speed: ENEMY_PROJECTILE_SPEED,
             previous_translation: initial_translation,
+            damage: Damage(1),
+            size: Vec2::splat(PROJECTILE_SIZE),
         },
         EnemyProjectile,
     ));
@@ -69,10 +107,11 @@ pub fn spawn_boss_projectile(
     let angle = dir.to_angle();
     let transform =
         Transform::from_translation(position).with_rotation(Quat::from_rotation_z(angle));
+    let size = Vec2::new(16.0, 8.0);
     commands.spawn((
         Sprite {
             image: assets.boss_projectile.clone(),
-            custom_size: Some(Vec2::new(16.0, 8.0)),
+            custom_size: Some(size),
             ..default()
         },
         transform,
@@ -80,11 +119,22 @@ pub fn spawn_boss_projectile(
             direction: dir,
             speed: PROJECTILE_SPEED * 0.85,
             previous_translation: Vec2::new(position.x, position.y),
+            damage: Damage(1),
+            size: rotated_extent(size, angle),
         },
         EnemyProjectile,
     ));
 }
 
+/// Bounding box of a `size` rectangle turned by `angle`.
+fn rotated_extent(size: Vec2, angle: f32) -> Vec2 {
+    let (sin, cos) = angle.sin_cos();
+    Vec2::new(
+        size.x * cos.abs() + size.y * sin.abs(),
+        size.x * sin.abs() + size.y * cos.abs(),
+    )
+}
+
 pub fn one_shot_lifetime_system(
     mut commands: Commands,
     time: Res<Time>,
@@ -0,0 +1,282 @@
+use crate::player::components::{ActionFrame, PlayerActions};
+use crate::player::player_input_system::gather_player_actions;
+use crate::restart::GameRestartRequested;
+use crate::rng::GameRng;
+use crate::systems::PlayerControl;
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use std::path::PathBuf;
+
+const REPLAY_MAGIC: &[u8; 4] = b"GDRP";
+const REPLAY_VERSION: u8 = 2;
+
+/// RNG seed plus the input of every simulation tick of a run.
+///
+/// Stored as the magic bytes, a version byte, the little-endian seed and then
+/// run-length encoded `(count: u16, buttons: u8, stick: [i8; 2])` records.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Replay {
+    pub seed: u64,
+    pub frames: Vec<ActionFrame>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReplayError {
+    #[error("not a replay file")]
+    BadMagic,
+    #[error("unsupported replay version {0}")]
+    UnsupportedVersion(u8),
+    #[error("replay data is truncated")]
+    Truncated,
+}
+
+impl Replay {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(REPLAY_MAGIC);
+        bytes.push(REPLAY_VERSION);
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+
+        let mut frames = self.frames.iter().copied().peekable();
+        while let Some(frame) = frames.next() {
+            let mut count: u16 = 1;
+            while count < u16::MAX && frames.peek() == Some(&frame) {
+                frames.next();
+                count += 1;
+            }
+            bytes.extend_from_slice(&count.to_le_bytes());
+            bytes.push(frame.buttons);
+            bytes.extend(frame.stick.map(|axis| axis as u8));
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ReplayError> {
+        let Some(body) = bytes.strip_prefix(REPLAY_MAGIC) else {
+            return Err(ReplayError::BadMagic);
+        };
+        let (&version, body) = body.split_first().ok_or(ReplayError::Truncated)?;
+        if version != REPLAY_VERSION {
+            return Err(ReplayError::UnsupportedVersion(version));
+        }
+        let (seed, body) = body
+            .split_first_chunk::<8>()
+            .ok_or(ReplayError::Truncated)?;
+
+        let mut runs = body.chunks_exact(5);
+        let mut frames = Vec::new();
+        for run in runs.by_ref() {
+            let count = u16::from_le_bytes([run[0], run[1]]);
+            let frame = ActionFrame {
+                buttons: run[2],
+                stick: [run[3] as i8, run[4] as i8],
+            };
+            frames.extend(std::iter::repeat_n(frame, count as usize));
+        }
+        if !runs.remainder().is_empty() {
+            return Err(ReplayError::Truncated);
+        }
+
+        Ok(Self {
+            seed: u64::from_le_bytes(*seed),
+            frames,
+        })
+    }
+}
+
+/// Present while the run is being recorded. Written to `path` on exit.
+#[derive(Resource, Default)]
+pub struct InputRecording {
+    pub replay: Replay,
+    pub path: Option<PathBuf>,
+}
+
+/// Present while input comes from a replay instead of the keyboard. Once the
+/// frames run out the player gets neutral input.
+#[derive(Resource)]
+pub struct InputPlayback {
+    replay: Replay,
+    cursor: usize,
+}
+
+impl InputPlayback {
+    pub fn new(replay: Replay) -> Self {
+        Self { replay, cursor: 0 }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.cursor >= self.replay.frames.len()
+    }
+
+    fn next_frame(&mut self) -> ActionFrame {
+        let frame = self
+            .replay
+            .frames
+            .get(self.cursor)
+            .copied()
+            .unwrap_or_default();
+        self.cursor += 1;
+        frame
+    }
+}
+
+/// Reads `GUNDAY_RECORD=<path>` and `GUNDAY_REPLAY=<path>` on native builds.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn configure_from_env(app: &mut App) {
+    if let Some(path) = std::env::var_os("GUNDAY_REPLAY") {
+        match std::fs::read(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|bytes| Replay::from_bytes(&bytes).map_err(|err| err.to_string()))
+        {
+            Ok(replay) => {
+                app.insert_resource(InputPlayback::new(replay));
+            }
+            Err(err) => warn!("could not load replay {}: {err}", path.display()),
+        }
+    }
+
+    if let Some(path) = std::env::var_os("GUNDAY_RECORD") {
+        app.insert_resource(InputRecording {
+            replay: Replay::default(),
+            path: Some(PathBuf::from(path)),
+        });
+    }
+}
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreStartup, sync_replay_seed)
+            .add_systems(
+                FixedPreUpdate,
+                (
+                    replay_player_actions.run_if(resource_exists::<InputPlayback>),
+                    record_player_actions
+                        .after(gather_player_actions)
+                        .after(replay_player_actions)
+                        .run_if(resource_exists::<InputRecording>),
+                ),
+            )
+            .add_systems(
+                Update,
+                restart_recording.run_if(resource_exists::<InputRecording>),
+            )
+            .add_systems(Last, save_recording_on_exit);
+    }
+}
+
+fn sync_replay_seed(
+    mut rng: ResMut<GameRng>,
+    playback: Option<Res<InputPlayback>>,
+    recording: Option<ResMut<InputRecording>>,
+) {
+    if let Some(playback) = playback {
+        rng.reseed(playback.replay.seed);
+    }
+    if let Some(mut recording) = recording {
+        recording.replay.seed = rng.seed();
+    }
+}
+
+fn replay_player_actions(
+    time: Res<Time>,
+    control: Option<Res<PlayerControl>>,
+    mut playback: ResMut<InputPlayback>,
+    mut actions: ResMut<PlayerActions>,
+) {
+    let frame = playback.next_frame();
+    if control.is_some_and(|ctrl| !ctrl.enabled) {
+        actions.reset();
+        return;
+    }
+    actions.apply_frame(frame, time.delta_secs());
+}
+
+fn record_player_actions(actions: Res<PlayerActions>, mut recording: ResMut<InputRecording>) {
+    recording.replay.frames.push(actions.frame);
+}
+
+/// A restart reuses the seed, so the recording only keeps the frames of the
+/// latest run, the same ones `RunLog` signs.
+fn restart_recording(
+    mut restart: EventReader<GameRestartRequested>,
+    mut recording: ResMut<InputRecording>,
+) {
+    if restart.read().last().is_some() {
+        recording.replay.frames.clear();
+    }
+}
+
+fn save_recording_on_exit(exit: EventReader<AppExit>, recording: Option<Res<InputRecording>>) {
+    if exit.is_empty() {
+        return;
+    }
+    let Some(recording) = recording else {
+        return;
+    };
+    let Some(path) = &recording.path else {
+        return;
+    };
+    if let Err(err) = std::fs::write(path, recording.replay.to_bytes()) {
+        error!("could not save replay {}: {err}", path.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replay() -> Replay {
+        let shoot = ActionFrame {
+            buttons: ActionFrame::SHOOT,
+            stick: [127, -127],
+        };
+        let mut frames = vec![ActionFrame::default(); u16::MAX as usize + 3];
+        frames.extend([shoot; 2]);
+        frames.push(ActionFrame::default());
+        Replay { seed: 42, frames }
+    }
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let replay = replay();
+        let bytes = replay.to_bytes();
+        // The long idle stretch splits into two runs.
+        assert_eq!(bytes.len(), 4 + 1 + 8 + 4 * 5);
+        assert_eq!(Replay::from_bytes(&bytes).unwrap(), replay);
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let bytes = replay().to_bytes();
+        for len in [4, 5, 12, bytes.len() - 1] {
+            assert!(matches!(
+                Replay::from_bytes(&bytes[..len]),
+                Err(ReplayError::Truncated)
+            ));
+        }
+        assert!(matches!(
+            Replay::from_bytes(b"GD"),
+            Err(ReplayError::BadMagic)
+        ));
+    }
+
+    #[test]
+    fn restart_keeps_only_the_new_run() {
+        let mut app = App::new();
+        app.add_event::<GameRestartRequested>()
+            .insert_resource(InputRecording {
+                replay: replay(),
+                path: None,
+            })
+            .add_systems(Update, restart_recording);
+
+        app.world_mut().send_event(GameRestartRequested);
+        app.update();
+
+        let recording = app.world().resource::<InputRecording>();
+        assert_eq!(recording.replay.seed, 42);
+        assert!(recording.replay.frames.is_empty());
+    }
+}
@@ -0,0 +1,68 @@
+use crate::checkpoint::components::CheckpointBanner;
+use crate::checkpoint::continue_system::ContinuePromptUi;
+use crate::game_state::GameState;
+use crate::level::LevelScoped;
+use crate::player::components::{Player, PlayerActions};
+use crate::player::setup_player;
+use crate::player::track_player_position_system::PositionTriggerState;
+use crate::projectile::components::OneShotLifetime;
+use crate::rng::GameRng;
+use crate::score::Score;
+use crate::soundtrack::BossSoundtrackState;
+use crate::spawn::EdgeSpawnManager;
+use crate::systems::{PlayerControl, WinMusic};
+use bevy::prelude::*;
+
+/// Sent to play the whole campaign again from the first level without
+/// reloading the app, from the menus or the page through `restart_game`.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct GameRestartRequested;
+
+type Gameplay = Or<(
+    LevelScoped,
+    With<Player>,
+    With<OneShotLifetime>,
+    With<CheckpointBanner>,
+    With<ContinuePromptUi>,
+)>;
+
+pub struct RestartPlugin;
+
+impl Plugin for RestartPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<GameRestartRequested>()
+            .add_systems(Update, restart_game_system);
+    }
+}
+
+/// Clears the run and spawns a fresh player. The level reloads itself and
+/// the soundtrack resets on the same event. The RNG goes back to the start of
+/// the run's seed, so a restarted run plays out like the first one.
+fn restart_game_system(
+    mut commands: Commands,
+    mut requests: EventReader<GameRestartRequested>,
+    mut rng: ResMut<GameRng>,
+    gameplay: Query<Entity, Gameplay>,
+) {
+    if requests.read().last().is_none() {
+        return;
+    }
+
+    for entity in gameplay.iter() {
+        commands.entity(entity).try_despawn();
+    }
+
+    let seed = rng.seed();
+    rng.reseed(seed);
+
+    commands.insert_resource(GameState::default());
+    commands.insert_resource(PositionTriggerState::default());
+    commands.insert_resource(EdgeSpawnManager::default());
+    commands.insert_resource(BossSoundtrackState::default());
+    commands.insert_resource(WinMusic::default());
+    commands.insert_resource(PlayerControl::default());
+    commands.insert_resource(PlayerActions::default());
+    commands.insert_resource(Score::default());
+
+    commands.run_system_cached(setup_player);
+}
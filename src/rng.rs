@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use std::ops::Range;
+
+/// Source of all gameplay randomness. Re-seeding it before a run makes the
+/// run reproducible from the seed plus the recorded input.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    rng: fastrand::Rng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: fastrand::Rng::with_seed(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.rng.bool()
+    }
+
+    pub fn f32(&mut self) -> f32 {
+        self.rng.f32()
+    }
+
+    pub fn usize(&mut self, range: Range<usize>) -> usize {
+        self.rng.usize(range)
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(fastrand::u64(..))
+    }
+}
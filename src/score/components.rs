@@ -0,0 +1,41 @@
+use super::config::SCORE_CONFIG;
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub struct Score {
+    pub points: u32,
+    /// Kills in the current chain.
+    pub combo: u32,
+    pub combo_timer: f32,
+}
+
+impl Score {
+    pub fn multiplier(&self) -> u32 {
+        (1 + self.combo / SCORE_CONFIG.kills_per_multiplier_step).min(SCORE_CONFIG.max_multiplier)
+    }
+
+    /// Adds a kill worth `base` points at the current multiplier and extends the chain.
+    pub fn add_kill(&mut self, base: u32) {
+        self.points = self.points.saturating_add(base * self.multiplier());
+        self.combo += 1;
+        self.combo_timer = SCORE_CONFIG.combo_window;
+    }
+
+    pub fn add_points(&mut self, points: u32) {
+        self.points = self.points.saturating_add(points);
+    }
+
+    pub fn break_combo(&mut self) {
+        self.combo = 0;
+        self.combo_timer = 0.0;
+    }
+}
+
+#[derive(Component)]
+pub struct ScoreHudRoot;
+
+#[derive(Component)]
+pub struct ScoreText;
+
+#[derive(Component)]
+pub struct ComboText;
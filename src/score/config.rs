@@ -0,0 +1,46 @@
+use crate::components::EnemyKind;
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ScoreConfig {
+    pub robot_points: u32,
+    pub enemy_b_points: u32,
+    pub enemy_c_points: u32,
+    pub miniboss_points: u32,
+    pub boss_points: u32,
+    /// Seconds the combo survives without another kill.
+    pub combo_window: f32,
+    /// Kills in a chain needed to raise the multiplier by one.
+    pub kills_per_multiplier_step: u32,
+    pub max_multiplier: u32,
+    pub hud_offset: Vec2,
+    pub font_size: f32,
+    pub combo_font_size: f32,
+    pub combo_line_spacing: f32,
+}
+
+pub const SCORE_CONFIG: ScoreConfig = ScoreConfig {
+    robot_points: 100,
+    enemy_b_points: 150,
+    enemy_c_points: 300,
+    miniboss_points: 2000,
+    boss_points: 10000,
+    combo_window: 2.5,
+    kills_per_multiplier_step: 3,
+    max_multiplier: 5,
+    hud_offset: Vec2::new(12.0, 10.0),
+    font_size: 24.0,
+    combo_font_size: 18.0,
+    combo_line_spacing: 4.0,
+};
+
+impl ScoreConfig {
+    pub const fn points_for(&self, kind: EnemyKind) -> u32 {
+        match kind {
+            EnemyKind::Robot => self.robot_points,
+            EnemyKind::EnemyB => self.enemy_b_points,
+            EnemyKind::EnemyC => self.enemy_c_points,
+            EnemyKind::Miniboss => self.miniboss_points,
+        }
+    }
+}
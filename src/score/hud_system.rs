@@ -0,0 +1,79 @@
+use super::components::{ComboText, Score, ScoreHudRoot, ScoreText};
+use super::config::SCORE_CONFIG;
+use crate::components::MainCamera;
+use crate::constants::{SCREEN_HEIGHT, SCREEN_WIDTH, Z_FOREGROUND};
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+pub fn setup_score_hud(
+    mut commands: Commands,
+    camera_query: Query<Entity, With<MainCamera>>,
+    existing_ui: Query<Entity, With<ScoreHudRoot>>,
+) {
+    if existing_ui.iter().next().is_some() {
+        return;
+    }
+    let Some(camera_entity) = camera_query.iter().next() else {
+        return;
+    };
+
+    let right = SCREEN_WIDTH / 2.0 - SCORE_CONFIG.hud_offset.x;
+    let top = SCREEN_HEIGHT / 2.0 - SCORE_CONFIG.hud_offset.y;
+    let combo_top = top - SCORE_CONFIG.font_size - SCORE_CONFIG.combo_line_spacing;
+
+    commands.entity(camera_entity).with_children(|parent| {
+        parent
+            .spawn((
+                ScoreHudRoot,
+                Transform::from_xyz(0.0, 0.0, Z_FOREGROUND + 10.0),
+                GlobalTransform::default(),
+                Visibility::Visible,
+                InheritedVisibility::default(),
+            ))
+            .with_children(|root| {
+                root.spawn((
+                    Text2d::new(format_score(0)),
+                    TextFont::from_font_size(SCORE_CONFIG.font_size),
+                    TextColor(Color::WHITE),
+                    Anchor::TopRight,
+                    Transform::from_xyz(right, top, 0.0),
+                    ScoreText,
+                ));
+                root.spawn((
+                    Text2d::default(),
+                    TextFont::from_font_size(SCORE_CONFIG.combo_font_size),
+                    TextColor(Color::srgb(1.0, 0.85, 0.3)),
+                    Anchor::TopRight,
+                    Transform::from_xyz(right, combo_top, 0.0),
+                    Visibility::Hidden,
+                    ComboText,
+                ));
+            });
+    });
+}
+
+pub fn score_hud_update_system(
+    score: Res<Score>,
+    mut score_text: Query<&mut Text2d, (With<ScoreText>, Without<ComboText>)>,
+    mut combo_text: Query<(&mut Text2d, &mut Visibility), With<ComboText>>,
+) {
+    if !score.is_changed() {
+        return;
+    }
+    for mut text in score_text.iter_mut() {
+        text.0 = format_score(score.points);
+    }
+    let multiplier = score.multiplier();
+    for (mut text, mut visibility) in combo_text.iter_mut() {
+        if multiplier > 1 {
+            text.0 = format!("COMBO x{multiplier}");
+            *visibility = Visibility::Inherited;
+        } else {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+fn format_score(points: u32) -> String {
+    format!("{points:07}")
+}
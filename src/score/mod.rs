@@ -0,0 +1,11 @@
+pub mod components;
+pub mod config;
+pub mod hud_system;
+pub mod plugin;
+pub mod score_system;
+
+pub use components::{ComboText, Score, ScoreHudRoot, ScoreText};
+pub use config::SCORE_CONFIG;
+pub use hud_system::{score_hud_update_system, setup_score_hud};
+pub use plugin::ScorePlugin;
+pub use score_system::{score_combo_decay_system, score_kill_system};
@@ -0,0 +1,17 @@
+use super::*;
+use crate::systems::setup_camera;
+use bevy::prelude::*;
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Score>()
+            .add_systems(Startup, setup_score_hud.after(setup_camera))
+            .add_systems(
+                FixedUpdate,
+                (score_combo_decay_system, score_kill_system).chain(),
+            )
+            .add_systems(Update, score_hud_update_system);
+    }
+}
@@ -0,0 +1,38 @@
+use super::components::Score;
+use super::config::SCORE_CONFIG;
+use crate::boss::events::BossDefeatedEvent;
+use crate::components::EnemyDefeatedEvent;
+use crate::player::PlayerDamagedEvent;
+use bevy::prelude::*;
+
+pub fn score_kill_system(
+    mut score: ResMut<Score>,
+    mut defeated: EventReader<EnemyDefeatedEvent>,
+    mut boss_defeated: EventReader<BossDefeatedEvent>,
+) {
+    for event in defeated.read() {
+        score.add_kill(SCORE_CONFIG.points_for(event.kind));
+    }
+    for _ in boss_defeated.read() {
+        score.add_kill(SCORE_CONFIG.boss_points);
+    }
+}
+
+/// Lets the combo run out and drops it as soon as the player gets hit.
+pub fn score_combo_decay_system(
+    time: Res<Time>,
+    mut score: ResMut<Score>,
+    mut damaged: EventReader<PlayerDamagedEvent>,
+) {
+    if damaged.read().count() > 0 {
+        score.break_combo();
+        return;
+    }
+    if score.combo == 0 {
+        return;
+    }
+    score.combo_timer -= time.delta_secs();
+    if score.combo_timer <= 0.0 {
+        score.break_combo();
+    }
+}
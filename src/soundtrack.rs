@@ -1,13 +1,191 @@
-use std::collections::BTreeSet;
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
 
 use crate::boss::events::BossDefeatedEvent;
+use crate::game_state::{GamePhase, GamePhaseChanged};
+use crate::level::{CurrentLevel, LevelData, LevelStarted};
+use crate::restart::GameRestartRequested;
 use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
     audio::{AudioPlayer, AudioSink, AudioSource, PlaybackSettings, Volume},
     prelude::*,
 };
+use serde::{Deserialize, Deserializer, de};
+
+const SOUNDTRACK_MANIFEST_PATH: &str = "ost/soundtrack.soundtrack.ron";
+const DUCK_FADE_SECONDS: f32 = 0.25;
+
+/// Stems, loop length, tempo, named track sets and stingers of the
+/// soundtrack. Sets list stems by their position in `stems`, counting from 1.
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+pub struct SoundtrackManifest {
+    pub loop_seconds: f32,
+    /// Beats per minute. The loop is expected to hold a whole number of beats.
+    pub bpm: f32,
+    #[serde(default = "default_beats_per_bar")]
+    pub beats_per_bar: u32,
+    pub stems: Vec<StemData>,
+    pub sets: HashMap<TrackSetName, Vec<usize>>,
+    #[serde(default)]
+    pub stingers: HashMap<Stinger, StingerData>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct StemData {
+    pub path: String,
+    /// Seconds the stem takes to fade in when its set starts.
+    #[serde(default)]
+    pub fade_in_offset: f32,
+    /// Seconds the stem takes to fade out when its set ends.
+    #[serde(default)]
+    pub fade_out: f32,
+}
+
+/// Moments that play a one-shot over the soundtrack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum Stinger {
+    PhaseStart,
+    MinibossDown,
+    CheckpointReached,
+    GameOver,
+    Win,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct StingerData {
+    pub path: String,
+    /// Level the stems duck to while the stinger plays.
+    #[serde(default = "default_stinger_duck")]
+    pub duck: f32,
+    /// Seconds the stems take to duck.
+    #[serde(default = "default_stinger_attack")]
+    pub attack: f32,
+    /// Seconds the stems take to come back, from the first loop boundary
+    /// after the stinger has finished.
+    #[serde(default = "default_stinger_release")]
+    pub release: f32,
+}
+
+fn default_beats_per_bar() -> u32 {
+    4
+}
 
-const TRACK_COUNT: usize = 9;
-pub const TRACK_LOOP_DURATION_SECONDS: f32 = 7.059;
+fn default_stinger_duck() -> f32 {
+    0.35
+}
+
+fn default_stinger_attack() -> f32 {
+    0.1
+}
+
+fn default_stinger_release() -> f32 {
+    1.0
+}
+
+impl SoundtrackManifest {
+    fn validate(&self) -> Result<(), SoundtrackManifestError> {
+        if self.loop_seconds <= 0.0 {
+            return Err(SoundtrackManifestError::LoopLength(self.loop_seconds));
+        }
+        if self.bpm <= 0.0 || self.beats_per_bar == 0 {
+            return Err(SoundtrackManifestError::Tempo {
+                bpm: self.bpm,
+                beats_per_bar: self.beats_per_bar,
+            });
+        }
+        if let Some(set) = TrackSetName::REQUIRED
+            .into_iter()
+            .find(|set| !self.sets.contains_key(set))
+        {
+            return Err(SoundtrackManifestError::MissingTrackSet(set));
+        }
+        for (set, stems) in &self.sets {
+            if let Some(&stem) = stems
+                .iter()
+                .find(|&&stem| stem == 0 || stem > self.stems.len())
+            {
+                return Err(SoundtrackManifestError::StemOutOfRange {
+                    set: set.clone(),
+                    stem,
+                    count: self.stems.len(),
+                });
+            }
+        }
+        for (&stinger, data) in &self.stingers {
+            if !(0.0..=1.0).contains(&data.duck) || data.attack < 0.0 || data.release < 0.0 {
+                return Err(SoundtrackManifestError::StingerDuck(stinger));
+            }
+        }
+        Ok(())
+    }
+
+    /// Every track set the level's triggers ask for has to be in `sets`.
+    fn validate_level(&self, level: &LevelData) -> Result<(), SoundtrackManifestError> {
+        for trigger in &level.triggers {
+            let Some(set) = &trigger.track_set else {
+                continue;
+            };
+            if !self.sets.contains_key(set) {
+                return Err(SoundtrackManifestError::UnknownTrackSet {
+                    set: set.clone(),
+                    x: trigger.x,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct SoundtrackManifestLoader;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SoundtrackManifestError {
+    #[error("could not read soundtrack manifest: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse soundtrack manifest: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("loop length must be positive, got {0}")]
+    LoopLength(f32),
+    #[error("tempo must be positive, got {bpm} bpm with {beats_per_bar} beats per bar")]
+    Tempo { bpm: f32, beats_per_bar: u32 },
+    #[error("manifest has no track set {0}, which the game switches to by name")]
+    MissingTrackSet(TrackSetName),
+    #[error("track set {set} uses stem {stem}, but stems are numbered 1 to {count}")]
+    StemOutOfRange {
+        set: TrackSetName,
+        stem: usize,
+        count: usize,
+    },
+    #[error("stinger {0:?} needs a duck level from 0 to 1 and non-negative attack and release")]
+    StingerDuck(Stinger),
+    #[error("level trigger at x {x} asks for track set {set}, which the manifest doesn't have")]
+    UnknownTrackSet { set: TrackSetName, x: f32 },
+}
+
+impl AssetLoader for SoundtrackManifestLoader {
+    type Asset = SoundtrackManifest;
+    type Settings = ();
+    type Error = SoundtrackManifestError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<SoundtrackManifest, SoundtrackManifestError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let manifest: SoundtrackManifest = ron::de::from_bytes(&bytes)?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["soundtrack.ron"]
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 struct TrackTiming {
@@ -15,57 +193,69 @@ struct TrackTiming {
     fade_out_seconds: f32,
 }
 
-impl TrackTiming {
-    const fn new(fade_in_offset_seconds: f32, fade_out_seconds: f32) -> Self {
+impl From<&StemData> for TrackTiming {
+    fn from(stem: &StemData) -> Self {
         Self {
-            fade_in_offset_seconds,
-            fade_out_seconds,
+            fade_in_offset_seconds: stem.fade_in_offset,
+            fade_out_seconds: stem.fade_out,
         }
     }
 }
 
-const TRACK_TIMINGS: [TrackTiming; TRACK_COUNT] = [
-    TrackTiming::new(0.0, 0.0),
-    TrackTiming::new(0.0, 0.0),
-    TrackTiming::new(0.5, 1.0),
-    TrackTiming::new(0.0, 0.0),
-    TrackTiming::new(0.0, 0.0),
-    TrackTiming::new(0.0, 0.0),
-    TrackTiming::new(0.0, 1.0),
-    TrackTiming::new(0.2, 0.0),
-    TrackTiming::new(0.66, 0.0),
-];
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub enum TrackSetName {
-    Basic,
-    Ambient,
-    LightAction,
-    HeavyAction,
-    LightLab,
-    HeavyLab,
-    Entering,
-    BossStage1,
-    BossStage2,
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct StingerDuck {
+    level: f32,
+    attack_seconds: f32,
+    release_seconds: f32,
 }
 
-impl TrackSetName {
-    pub fn tracks(self) -> &'static [usize] {
-        match self {
-            TrackSetName::Basic => &[1],
-            TrackSetName::Ambient => &[1, 2],
-            TrackSetName::LightAction => &[1, 2, 3],
-            TrackSetName::HeavyAction => &[1, 2, 3, 4],
-            TrackSetName::LightLab => &[1, 2, 6],
-            TrackSetName::HeavyLab => &[1, 2, 5, 6],
-            TrackSetName::Entering => &[7],
-            TrackSetName::BossStage1 => &[7, 8],
-            TrackSetName::BossStage2 => &[8, 9],
+impl From<&StingerData> for StingerDuck {
+    fn from(stinger: &StingerData) -> Self {
+        Self {
+            level: stinger.duck,
+            attack_seconds: stinger.attack,
+            release_seconds: stinger.release,
         }
     }
+}
+
+/// A track set from the manifest. Written as a bare identifier in RON, e.g.
+/// `track_set: Some(LightAction)`, so level files can name any set.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TrackSetName(Cow<'static, str>);
+
+impl TrackSetName {
+    pub const BASIC: Self = Self(Cow::Borrowed("Basic"));
+    pub const AMBIENT: Self = Self(Cow::Borrowed("Ambient"));
+    pub const BOSS_STAGE_2: Self = Self(Cow::Borrowed("BossStage2"));
+
+    /// Sets the game switches to by name, so every manifest needs them.
+    const REQUIRED: [Self; 3] = [Self::BASIC, Self::AMBIENT, Self::BOSS_STAGE_2];
+}
+
+impl fmt::Display for TrackSetName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for TrackSetName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NameVisitor;
+
+        impl de::Visitor<'_> for NameVisitor {
+            type Value = TrackSetName;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a track set name")
+            }
+
+            fn visit_str<E: de::Error>(self, name: &str) -> Result<TrackSetName, E> {
+                Ok(TrackSetName(Cow::Owned(name.to_owned())))
+            }
+        }
 
-    pub fn as_set(self) -> BTreeSet<usize> {
-        self.tracks().iter().copied().collect()
+        deserializer.deserialize_identifier(NameVisitor)
     }
 }
 
@@ -73,64 +263,218 @@ pub struct SoundtrackPlugin;
 
 impl Plugin for SoundtrackPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<SoundtrackSetRequest>()
+        app.init_asset::<SoundtrackManifest>()
+            .init_asset_loader::<SoundtrackManifestLoader>()
+            .add_event::<SoundtrackSetRequest>()
+            .add_event::<MusicBeat>()
+            .add_event::<StingerRequest>()
             .init_resource::<SoundtrackDebugState>()
             .init_resource::<BossSoundtrackState>()
-            .add_systems(Startup, preload_soundtrack_tracks)
-            .add_systems(
-                Startup,
-                setup_soundtrack_entities.after(preload_soundtrack_tracks),
-            )
+            .add_systems(Startup, load_soundtrack_manifest)
             .add_systems(
                 Update,
                 (
+                    setup_soundtrack_stems,
                     soundtrack_loading_gate,
                     process_soundtrack_requests,
+                    phase_start_stinger,
+                    play_stinger_requests,
                     update_soundtrack_loop,
+                    emit_music_beats,
                     update_soundtrack_fades,
                     handle_soundtrack_activation_effects,
+                    restart_soundtrack_on_level_start,
+                    reset_soundtrack_on_restart,
+                    update_soundtrack_duck,
                 )
                     .chain(),
-            );
+            )
+            .add_systems(Update, check_level_track_sets);
     }
 }
 
+/// The manifest, and one handle per stem and stinger once it has loaded.
 #[derive(Resource)]
 pub struct SoundtrackHandles {
-    pub tracks: [Handle<AudioSource>; TRACK_COUNT],
+    pub manifest: Handle<SoundtrackManifest>,
+    pub tracks: Vec<Handle<AudioSource>>,
+    pub stingers: HashMap<Stinger, Handle<AudioSource>>,
 }
 
 #[derive(Resource)]
 pub(crate) struct SoundtrackController {
     track_entities: Vec<Entity>,
     track_states: Vec<TrackRuntimeState>,
+    track_timings: Vec<TrackTiming>,
+    sets: HashMap<TrackSetName, BTreeSet<usize>>,
+    stinger_ducks: HashMap<Stinger, StingerDuck>,
     active_set: TrackSetName,
     pending_set: Option<TrackSetName>,
     loop_timer: Timer,
     loop_counter: u64,
+    beat_seconds: f32,
+    beats_per_bar: u32,
+    /// Loop and beat within it that `emit_music_beats` sends next.
+    next_beat: (u64, u32),
     ready: bool,
     apply_at_half: bool,
     half_mark_passed: bool,
+    /// Volume multiplier over every track, eased towards `duck_target` at
+    /// `duck_rate` per second. `update_soundtrack_fades` and track switches
+    /// scale by it.
+    duck: f32,
+    duck_target: f32,
+    duck_rate: f32,
+    duck_level: f32,
+    stinger_level: f32,
+    /// The stinger holding the duck, and how long its release takes.
+    playing_stinger: Option<(Entity, f32)>,
+    /// Music bus gain from the mixer, applied on top of the duck.
+    music_gain: f32,
+    /// Master bus gain from the mixer, zero while muted. Applied after the
+    /// duck, so pausing and stingers never bring a muted soundtrack back.
+    master_gain: f32,
+    /// Set when a bus gain changes, so every player is rewritten once.
+    gain_changed: bool,
 }
 
 impl SoundtrackController {
-    fn new(track_entities: Vec<Entity>) -> Self {
-        let track_count = track_entities.len();
+    /// Silent until `configure` hands it the stems from the manifest.
+    fn new() -> Self {
         Self {
-            track_entities,
-            track_states: vec![TrackRuntimeState::default(); track_count],
-            active_set: TrackSetName::Basic,
+            track_entities: Vec::new(),
+            track_states: Vec::new(),
+            track_timings: Vec::new(),
+            sets: HashMap::new(),
+            stinger_ducks: HashMap::new(),
+            active_set: TrackSetName::BASIC,
             pending_set: None,
-            loop_timer: Timer::from_seconds(TRACK_LOOP_DURATION_SECONDS, TimerMode::Repeating),
+            loop_timer: Timer::default(),
             loop_counter: 0,
+            beat_seconds: 0.5,
+            beats_per_bar: 4,
+            next_beat: (0, 0),
             ready: false,
             apply_at_half: false,
             half_mark_passed: false,
+            duck: 1.0,
+            duck_target: 1.0,
+            duck_rate: 0.0,
+            duck_level: 1.0,
+            stinger_level: 1.0,
+            playing_stinger: None,
+            music_gain: 1.0,
+            master_gain: 1.0,
+            gain_changed: false,
         }
     }
 
-    fn has_pending(&self, name: TrackSetName) -> bool {
-        self.pending_set == Some(name)
+    fn configure(&mut self, manifest: &SoundtrackManifest, track_entities: Vec<Entity>) {
+        let starting = manifest
+            .sets
+            .get(&self.active_set)
+            .cloned()
+            .unwrap_or_default();
+        self.track_states = (1..=track_entities.len())
+            .map(|track_index| TrackRuntimeState {
+                level: if starting.contains(&track_index) {
+                    1.0
+                } else {
+                    0.0
+                },
+                ..default()
+            })
+            .collect();
+        self.track_entities = track_entities;
+        self.track_timings = manifest.stems.iter().map(TrackTiming::from).collect();
+        self.sets = manifest
+            .sets
+            .iter()
+            .map(|(name, stems)| (name.clone(), stems.iter().copied().collect()))
+            .collect();
+        self.stinger_ducks = manifest
+            .stingers
+            .iter()
+            .map(|(&stinger, data)| (stinger, StingerDuck::from(data)))
+            .collect();
+        self.loop_timer = Timer::from_seconds(manifest.loop_seconds, TimerMode::Repeating);
+        self.beat_seconds = 60.0 / manifest.bpm;
+        self.beats_per_bar = manifest.beats_per_bar;
+    }
+
+    /// Back to a fresh, unconfigured controller: no loop position, beat,
+    /// pending set, duck or stinger carries over. Only the mixer's gains
+    /// stay, already applied.
+    fn reset(&mut self) {
+        let (music_gain, master_gain) = (self.music_gain, self.master_gain);
+        *self = Self::new();
+        self.music_gain = music_gain;
+        self.master_gain = master_gain;
+        self.retarget_duck(0.0);
+        self.duck = self.duck_target;
+    }
+
+    fn loop_seconds(&self) -> f32 {
+        self.loop_timer.duration().as_secs_f32()
+    }
+
+    fn beats_per_loop(&self) -> u32 {
+        ((self.loop_seconds() / self.beat_seconds).round() as u32).max(1)
+    }
+
+    /// Stems of `name`, numbered from 1. Unknown sets are silent.
+    fn set_tracks(&self, name: &TrackSetName) -> BTreeSet<usize> {
+        self.sets.get(name).cloned().unwrap_or_default()
+    }
+
+    /// Whether every track has loaded and is playing in sync.
+    pub(crate) fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    /// Fades the whole soundtrack to `level` of its normal volume.
+    pub(crate) fn set_duck(&mut self, level: f32) {
+        self.duck_level = level.clamp(0.0, 1.0);
+        self.retarget_duck(DUCK_FADE_SECONDS);
+    }
+
+    pub(crate) fn set_music_gain(&mut self, gain: f32) {
+        self.music_gain = gain.clamp(0.0, 1.0);
+        self.gain_changed = true;
+        self.retarget_duck(DUCK_FADE_SECONDS);
+    }
+
+    /// Takes effect at once and stays out of the duck.
+    pub(crate) fn set_master_gain(&mut self, gain: f32) {
+        self.master_gain = gain.clamp(0.0, 1.0);
+        self.gain_changed = true;
+    }
+
+    /// Volume of a stem playing at full level.
+    fn output(&self) -> f32 {
+        self.duck * self.master_gain
+    }
+
+    fn stinger_volume(&self) -> f32 {
+        self.music_gain * self.master_gain
+    }
+
+    /// Ducks the stems under a stinger until the first loop boundary after
+    /// `entity`, the stinger's player, is gone.
+    fn start_stinger_duck(&mut self, entity: Entity, duck: StingerDuck) {
+        self.stinger_level = duck.level.clamp(0.0, 1.0);
+        self.playing_stinger = Some((entity, duck.release_seconds));
+        self.retarget_duck(duck.attack_seconds);
+    }
+
+    /// The duck reaches its new target in `fade_seconds`.
+    fn retarget_duck(&mut self, fade_seconds: f32) {
+        self.duck_target = self.duck_level * self.stinger_level * self.music_gain;
+        self.duck_rate = (self.duck_target - self.duck).abs() / fade_seconds.max(f32::EPSILON);
+    }
+
+    fn has_pending(&self, name: &TrackSetName) -> bool {
+        self.pending_set.as_ref() == Some(name)
     }
 
     pub(crate) fn request_track_set(&mut self, name: TrackSetName) -> TrackQueueResult {
@@ -139,10 +483,14 @@ impl SoundtrackController {
             return TrackQueueResult::unchanged();
         }
 
-        if self.has_pending(name) {
+        if self.has_pending(&name) {
             return TrackQueueResult::unchanged();
         }
 
+        if !self.sets.is_empty() && !self.sets.contains_key(&name) {
+            warn!("soundtrack manifest has no track set {name}");
+        }
+
         let apply_at_half = should_apply_at_half(self);
         self.set_pending(Some(name), apply_at_half);
         let eta = self.time_until_next_change(apply_at_half);
@@ -160,7 +508,7 @@ impl SoundtrackController {
             return None;
         }
 
-        let duration = TRACK_LOOP_DURATION_SECONDS;
+        let duration = self.loop_seconds();
         let elapsed = self.loop_timer.elapsed_secs().clamp(0.0, duration);
         let remaining_to_end = (duration - elapsed).max(0.0);
 
@@ -185,6 +533,7 @@ impl SoundtrackController {
             let state = &mut self.track_states[index];
             state.fade_in = None;
             state.fade_out = None;
+            state.level = 0.0;
             sink.set_volume(Volume::Linear(0.0));
             sink.mute();
         }
@@ -196,6 +545,8 @@ impl SoundtrackController {
 
 #[derive(Clone, Default)]
 struct TrackRuntimeState {
+    /// Stem volume before the duck and master gain, from 0 to 1.
+    level: f32,
     fade_in: Option<FadeInState>,
     fade_out: Option<FadeOutState>,
 }
@@ -218,7 +569,7 @@ fn should_apply_at_half(controller: &SoundtrackController) -> bool {
     if controller.half_mark_passed {
         return false;
     }
-    controller.loop_timer.elapsed_secs() < TRACK_LOOP_DURATION_SECONDS * 0.5
+    controller.loop_timer.elapsed_secs() < controller.loop_seconds() * 0.5
 }
 
 #[derive(Component)]
@@ -229,6 +580,42 @@ pub struct SoundtrackSetRequest {
     pub name: TrackSetName,
 }
 
+/// Sent on every beat of the soundtrack while it plays, counted from the
+/// start of the loop. Follows the music on real time, so it keeps ticking
+/// while paused and differs between replays of a run: only presentation
+/// may react to it, never gameplay.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MusicBeat {
+    pub bar: u32,
+    pub beat: u32,
+    pub loop_index: u64,
+}
+
+impl MusicBeat {
+    /// First beat of a bar.
+    pub fn is_downbeat(&self) -> bool {
+        self.beat == 0
+    }
+}
+
+/// Asks the soundtrack to play `stinger`; see `play_stinger`.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct StingerRequest {
+    pub stinger: Stinger,
+}
+
+/// Plays `stinger` over the music, ducking the stems until the next loop
+/// boundary after it ends. Stingers missing from the manifest are skipped.
+pub fn play_stinger(commands: &mut Commands, stinger: Stinger) {
+    commands.send_event(StingerRequest { stinger });
+}
+
+#[derive(Component)]
+struct SoundtrackStinger;
+
+type SoundtrackPlayer = Or<(With<SoundtrackTrack>, With<SoundtrackStinger>)>;
+type StingerSink = (With<SoundtrackStinger>, Without<SoundtrackTrack>);
+
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct TrackQueueResult {
     pub eta_seconds: Option<f32>,
@@ -256,7 +643,7 @@ impl Default for SoundtrackDebugState {
     fn default() -> Self {
         Self {
             loop_counter: 0,
-            active_set: TrackSetName::Basic,
+            active_set: TrackSetName::BASIC,
         }
     }
 }
@@ -266,33 +653,62 @@ pub(crate) struct BossSoundtrackState {
     pub(crate) music_cut_on_defeat: bool,
 }
 
-fn preload_soundtrack_tracks(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let tracks =
-        std::array::from_fn(|index| asset_server.load(format!("ost/track{}.ogg", index + 1)));
-    commands.insert_resource(SoundtrackHandles { tracks });
+fn load_soundtrack_manifest(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(SoundtrackHandles {
+        manifest: asset_server.load(SOUNDTRACK_MANIFEST_PATH),
+        tracks: Vec::new(),
+        stingers: HashMap::new(),
+    });
+    commands.insert_resource(SoundtrackController::new());
 }
 
-fn setup_soundtrack_entities(mut commands: Commands, handles: Res<SoundtrackHandles>) {
-    let mut track_entities = Vec::with_capacity(TRACK_COUNT);
-    for (index, handle) in handles.tracks.iter().enumerate() {
-        let handle = handle.clone();
-        let playback_settings = if index == 0 {
-            PlaybackSettings::LOOP.with_volume(Volume::Linear(1.0))
+/// Loads the stems and stingers and spawns the stem players once the
+/// manifest is in. The stems of the starting set play from the first loop,
+/// the rest silently.
+fn setup_soundtrack_stems(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    manifests: Res<Assets<SoundtrackManifest>>,
+    mut handles: ResMut<SoundtrackHandles>,
+    mut controller: ResMut<SoundtrackController>,
+) {
+    if !handles.tracks.is_empty() {
+        return;
+    }
+    let Some(manifest) = manifests.get(&handles.manifest) else {
+        return;
+    };
+
+    let starting = manifest
+        .sets
+        .get(&controller.active_set)
+        .cloned()
+        .unwrap_or_default();
+    let mut track_entities = Vec::with_capacity(manifest.stems.len());
+    for (index, stem) in manifest.stems.iter().enumerate() {
+        let handle: Handle<AudioSource> = asset_server.load(&stem.path);
+        let volume = if starting.contains(&(index + 1)) {
+            Volume::Linear(controller.output())
         } else {
-            PlaybackSettings::LOOP.with_volume(Volume::SILENT)
+            Volume::SILENT
         };
         let entity = commands
             .spawn((
                 Name::new(format!("soundtrack_track_{:02}", index + 1)),
                 SoundtrackTrack,
-                AudioPlayer::new(handle),
-                playback_settings,
+                AudioPlayer::new(handle.clone()),
+                PlaybackSettings::LOOP.with_volume(volume),
             ))
             .id();
+        handles.tracks.push(handle);
         track_entities.push(entity);
     }
-    let controller = SoundtrackController::new(track_entities);
-    commands.insert_resource(controller);
+    handles.stingers = manifest
+        .stingers
+        .iter()
+        .map(|(&stinger, data)| (stinger, asset_server.load(&data.path)))
+        .collect();
+    controller.configure(manifest, track_entities);
 }
 
 fn soundtrack_loading_gate(
@@ -302,7 +718,7 @@ fn soundtrack_loading_gate(
     mut debug_state: ResMut<SoundtrackDebugState>,
     sink_query: Query<Option<&AudioSink>, With<SoundtrackTrack>>,
 ) {
-    if controller.ready {
+    if controller.ready || handles.tracks.is_empty() {
         return;
     }
 
@@ -327,10 +743,11 @@ fn soundtrack_loading_gate(
     controller.ready = true;
     controller.loop_counter = 1;
     controller.loop_timer.reset();
+    controller.next_beat = (controller.loop_counter, 0);
     controller.half_mark_passed = false;
     controller.apply_at_half = false;
     debug_state.loop_counter = controller.loop_counter;
-    debug_state.active_set = controller.active_set;
+    debug_state.active_set = controller.active_set.clone();
 }
 
 fn process_soundtrack_requests(
@@ -338,22 +755,70 @@ fn process_soundtrack_requests(
     mut events: EventReader<SoundtrackSetRequest>,
 ) {
     for request in events.read() {
-        let _ = controller.request_track_set(request.name);
+        let _ = controller.request_track_set(request.name.clone());
+    }
+}
+
+/// Each fight opens with the phase start stinger.
+fn phase_start_stinger(mut commands: Commands, mut changed: EventReader<GamePhaseChanged>) {
+    let fights_started = changed
+        .read()
+        .filter(|change| {
+            matches!(
+                change.next,
+                GamePhase::HangarFight
+                    | GamePhase::MinibossFight
+                    | GamePhase::LabFight
+                    | GamePhase::BossFight
+            )
+        })
+        .count();
+    if fights_started > 0 {
+        play_stinger(&mut commands, Stinger::PhaseStart);
+    }
+}
+
+/// Stingers play on the music bus but, like sound effects, keep the volume
+/// they started at.
+fn play_stinger_requests(
+    mut commands: Commands,
+    handles: Res<SoundtrackHandles>,
+    mut controller: ResMut<SoundtrackController>,
+    mut requests: EventReader<StingerRequest>,
+) {
+    for request in requests.read() {
+        let (Some(clip), Some(&duck)) = (
+            handles.stingers.get(&request.stinger),
+            controller.stinger_ducks.get(&request.stinger),
+        ) else {
+            continue;
+        };
+
+        let entity = commands
+            .spawn((
+                Name::new(format!("soundtrack_stinger_{:?}", request.stinger)),
+                SoundtrackStinger,
+                AudioPlayer::new(clip.clone()),
+                PlaybackSettings::DESPAWN.with_volume(Volume::Linear(controller.stinger_volume())),
+            ))
+            .id();
+        controller.start_stinger_duck(entity, duck);
     }
 }
 
 fn update_soundtrack_loop(
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mut controller: ResMut<SoundtrackController>,
     mut debug_state: ResMut<SoundtrackDebugState>,
     mut sinks: Query<&mut AudioSink, With<SoundtrackTrack>>,
+    stingers: Query<(), With<SoundtrackStinger>>,
 ) {
     if !controller.ready {
         return;
     }
 
     controller.loop_timer.tick(time.delta());
-    let half_point = TRACK_LOOP_DURATION_SECONDS * 0.5;
+    let half_point = controller.loop_seconds() * 0.5;
 
     if controller.pending_set.is_some()
         && controller.apply_at_half
@@ -368,23 +833,68 @@ fn update_soundtrack_loop(
         controller.loop_counter += 1;
         controller.half_mark_passed = false;
 
+        let finished_stinger = controller
+            .playing_stinger
+            .filter(|&(stinger, _)| !stingers.contains(stinger));
+        if let Some((_, release_seconds)) = finished_stinger {
+            controller.playing_stinger = None;
+            controller.stinger_level = 1.0;
+            controller.retarget_duck(release_seconds);
+        }
+
         if controller.pending_set.is_some() {
             apply_pending_tracks(&mut controller, &mut sinks, debug_state.as_mut());
         } else {
-            debug_state.active_set = controller.active_set;
+            debug_state.active_set = controller.active_set.clone();
         }
         controller.loop_timer.reset();
         debug_state.loop_counter = controller.loop_counter;
-        debug_state.active_set = controller.active_set;
+        debug_state.active_set = controller.active_set.clone();
+    }
+}
+
+/// Sends every beat the loop timer has passed since the last frame, finishing
+/// the previous loop first if it just wrapped. Silent after the boss cut.
+fn emit_music_beats(
+    mut controller: ResMut<SoundtrackController>,
+    boss_state: Res<BossSoundtrackState>,
+    mut beats: EventWriter<MusicBeat>,
+) {
+    if !controller.ready {
+        return;
     }
+
+    let beats_per_loop = controller.beats_per_loop();
+    let beats_per_bar = controller.beats_per_bar;
+    let current_loop = controller.loop_counter;
+    let current_beat = ((controller.loop_timer.elapsed_secs() / controller.beat_seconds) as u32)
+        .min(beats_per_loop - 1);
+
+    let (mut loop_index, mut beat) = controller.next_beat;
+    while (loop_index, beat) <= (current_loop, current_beat) {
+        if !boss_state.music_cut_on_defeat {
+            beats.write(MusicBeat {
+                bar: beat / beats_per_bar,
+                beat: beat % beats_per_bar,
+                loop_index,
+            });
+        }
+        beat += 1;
+        if beat >= beats_per_loop {
+            loop_index += 1;
+            beat = 0;
+        }
+    }
+    controller.next_beat = (loop_index, beat);
 }
 
 fn update_soundtrack_fades(
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mut controller: ResMut<SoundtrackController>,
     mut sinks: Query<&mut AudioSink, With<SoundtrackTrack>>,
 ) {
     let delta = time.delta();
+    let output = controller.output();
 
     for entity_index in 0..controller.track_entities.len() {
         let entity = controller.track_entities[entity_index];
@@ -402,18 +912,19 @@ fn update_soundtrack_fades(
             fade_in_state.timer.tick(delta);
             let duration_secs = fade_in_state.timer.duration().as_secs_f32();
             if duration_secs <= f32::EPSILON {
-                sink.set_volume(Volume::Linear(1.0));
+                state.level = 1.0;
                 state.fade_in = None;
             } else {
                 let progress = (fade_in_state.timer.elapsed_secs() / duration_secs).clamp(0.0, 1.0);
-                sink.set_volume(Volume::Linear(progress));
+                state.level = progress;
                 sink.unmute();
 
                 if fade_in_state.timer.finished() {
-                    sink.set_volume(Volume::Linear(1.0));
+                    state.level = 1.0;
                     state.fade_in = None;
                 }
             }
+            sink.set_volume(Volume::Linear(state.level * output));
         }
 
         if let Some(fade_out_state) = state.fade_out.as_mut() {
@@ -422,12 +933,13 @@ fn update_soundtrack_fades(
             if duration_secs > 0.0 {
                 let progress =
                     (fade_out_state.timer.elapsed_secs() / duration_secs).clamp(0.0, 1.0);
-                let volume = fade_out_state.start_volume * (1.0 - progress);
-                sink.set_volume(Volume::Linear(volume.max(0.0)));
+                state.level = (fade_out_state.start_volume * (1.0 - progress)).max(0.0);
+                sink.set_volume(Volume::Linear(state.level * output));
             }
             sink.unmute();
 
             if fade_out_state.timer.finished() {
+                state.level = 0.0;
                 sink.set_volume(Volume::Linear(0.0));
                 sink.mute();
             }
@@ -454,10 +966,8 @@ fn apply_pending_tracks(
 
     if next_name != controller.active_set {
         apply_track_set(next_name, controller, sinks);
-        debug_state.active_set = controller.active_set;
-    } else {
-        debug_state.active_set = controller.active_set;
     }
+    debug_state.active_set = controller.active_set.clone();
 
     controller.apply_at_half = false;
 }
@@ -467,14 +977,15 @@ fn apply_track_set(
     controller: &mut SoundtrackController,
     sinks: &mut Query<&mut AudioSink, With<SoundtrackTrack>>,
 ) {
-    let desired_tracks = new_set.as_set();
-    let current_set = controller.active_set.as_set();
+    let desired_tracks = controller.set_tracks(&new_set);
+    let current_set = controller.set_tracks(&controller.active_set);
+    let output = controller.output();
 
     for (entity_index, entity) in controller.track_entities.iter().enumerate() {
         let track_index = entity_index + 1;
         let desired_active = desired_tracks.contains(&track_index);
         let was_active = current_set.contains(&track_index);
-        let timing = TRACK_TIMINGS[entity_index];
+        let timing = controller.track_timings[entity_index];
         let state = &mut controller.track_states[entity_index];
 
         let Ok(mut sink) = sinks.get_mut(*entity) else {
@@ -486,16 +997,14 @@ fn apply_track_set(
                 state.fade_out = None;
                 sink.unmute();
                 if state.fade_in.is_none() {
-                    sink.set_volume(Volume::Linear(1.0));
+                    state.level = 1.0;
+                    sink.set_volume(Volume::Linear(output));
                 }
             }
             (true, false) => {
                 state.fade_in = None;
                 if timing.fade_out_seconds > 0.0 {
-                    let start_volume = match sink.volume() {
-                        Volume::Linear(value) => value,
-                        _ => 1.0,
-                    };
+                    let start_volume = state.level;
                     let timer = Timer::from_seconds(timing.fade_out_seconds, TimerMode::Once);
                     sink.unmute();
                     state.fade_out = Some(FadeOutState {
@@ -504,6 +1013,7 @@ fn apply_track_set(
                     });
                 } else {
                     state.fade_out = None;
+                    state.level = 0.0;
                     sink.set_volume(Volume::Linear(0.0));
                     sink.mute();
                 }
@@ -512,17 +1022,20 @@ fn apply_track_set(
                 state.fade_out = None;
                 sink.unmute();
                 if timing.fade_in_offset_seconds > 0.0 {
+                    state.level = 0.0;
                     sink.set_volume(Volume::Linear(0.0));
                     let timer = Timer::from_seconds(timing.fade_in_offset_seconds, TimerMode::Once);
                     state.fade_in = Some(FadeInState { timer });
                 } else {
                     state.fade_in = None;
-                    sink.set_volume(Volume::Linear(1.0));
+                    state.level = 1.0;
+                    sink.set_volume(Volume::Linear(output));
                 }
             }
             (false, false) => {
                 state.fade_in = None;
                 if state.fade_out.is_none() {
+                    state.level = 0.0;
                     sink.set_volume(Volume::Linear(0.0));
                     sink.mute();
                 }
@@ -548,6 +1061,105 @@ fn handle_soundtrack_activation_effects(
     if boss_defeated && !boss_state.music_cut_on_defeat {
         boss_state.music_cut_on_defeat = true;
         controller.stop_all_tracks(&mut sinks);
-        debug_state.active_set = controller.active_set;
+        debug_state.active_set = controller.active_set.clone();
+    }
+}
+
+/// Checks each level against the manifest once both have loaded.
+fn check_level_track_sets(
+    current: Res<CurrentLevel>,
+    levels: Res<Assets<LevelData>>,
+    handles: Res<SoundtrackHandles>,
+    manifests: Res<Assets<SoundtrackManifest>>,
+    mut checked: Local<Option<AssetId<LevelData>>>,
+) -> Result {
+    if *checked == Some(current.handle.id()) {
+        return Ok(());
+    }
+    let (Some(level), Some(manifest)) = (
+        levels.get(&current.handle),
+        manifests.get(&handles.manifest),
+    ) else {
+        return Ok(());
+    };
+
+    *checked = Some(current.handle.id());
+    manifest.validate_level(level)?;
+    Ok(())
+}
+
+fn restart_soundtrack_on_level_start(
+    mut controller: ResMut<SoundtrackController>,
+    mut boss_state: ResMut<BossSoundtrackState>,
+    mut debug_state: ResMut<SoundtrackDebugState>,
+    mut sinks: Query<&mut AudioSink, With<SoundtrackTrack>>,
+    mut level_events: EventReader<LevelStarted>,
+) {
+    if level_events.read().last().is_none() || !boss_state.music_cut_on_defeat {
+        return;
+    }
+
+    boss_state.music_cut_on_defeat = false;
+    controller.stop_all_tracks(&mut sinks);
+    apply_track_set(TrackSetName::BASIC, &mut controller, &mut sinks);
+    debug_state.active_set = controller.active_set.clone();
+}
+
+/// A restarted game gets the soundtrack from the top, even mid boss fight.
+/// The stems can't seek, so they are respawned and `setup_soundtrack_stems`
+/// and the loading gate start them over like on boot.
+fn reset_soundtrack_on_restart(
+    mut commands: Commands,
+    mut restart: EventReader<GameRestartRequested>,
+    mut controller: ResMut<SoundtrackController>,
+    mut handles: ResMut<SoundtrackHandles>,
+    mut debug_state: ResMut<SoundtrackDebugState>,
+    players: Query<Entity, SoundtrackPlayer>,
+) {
+    if restart.read().last().is_none() {
+        return;
+    }
+
+    for entity in players.iter() {
+        commands.entity(entity).despawn();
+    }
+    controller.reset();
+    handles.tracks.clear();
+    *debug_state = SoundtrackDebugState::default();
+}
+
+/// Eases the duck level towards its target and rewrites every player's
+/// volume whenever it or a bus gain moves.
+fn update_soundtrack_duck(
+    time: Res<Time<Real>>,
+    mut controller: ResMut<SoundtrackController>,
+    mut tracks: Query<&mut AudioSink, With<SoundtrackTrack>>,
+    mut stingers: Query<&mut AudioSink, StingerSink>,
+) {
+    if controller.duck == controller.duck_target && !controller.gain_changed {
+        return;
+    }
+
+    let step = time.delta_secs() * controller.duck_rate;
+    let difference = controller.duck_target - controller.duck;
+    controller.duck += difference.clamp(-step, step);
+
+    let output = controller.output();
+    for (entity, state) in controller
+        .track_entities
+        .iter()
+        .zip(controller.track_states.iter())
+    {
+        if let Ok(mut sink) = tracks.get_mut(*entity) {
+            sink.set_volume(Volume::Linear(state.level * output));
+        }
+    }
+
+    if controller.gain_changed {
+        controller.gain_changed = false;
+        let volume = Volume::Linear(controller.stinger_volume());
+        for mut sink in stingers.iter_mut() {
+            sink.set_volume(volume);
+        }
     }
 }
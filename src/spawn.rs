@@ -14,24 +14,10 @@ use crate::enemy_c::components::{ENEMY_C_WIDTH, EnemyC};
 use crate::game_state::{GamePhase, GameState};
 use crate::player::components::Player;
 use bevy::prelude::*;
+use serde::Deserialize;
 
 const HANGAR_ENEMY_SPAWN_INTERVAL: f32 = 3.0;
 
-#[derive(Clone, Copy)]
-pub struct StaticSpawnCoordinates {
-    pub grenade_thrower_1: f32,
-    pub grenade_thrower_2: f32,
-    pub grenade_thrower_3: f32,
-    pub infected_enemy: f32,
-}
-
-pub const STATIC_SPAWN_COORDINATES: StaticSpawnCoordinates = StaticSpawnCoordinates {
-    grenade_thrower_1: 3487.0,
-    grenade_thrower_2: 4003.0,
-    grenade_thrower_3: 4468.0,
-    infected_enemy: 6680.0,
-};
-
 #[derive(Resource, Default)]
 pub struct EdgeSpawnManager {
     pub definitions: Vec<EdgeSpawnDefinition>,
@@ -47,7 +33,7 @@ pub struct EdgeSpawnDefinition {
     pub spawned: bool,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 pub enum ScreenEdge {
     Left,
     Right,
@@ -65,10 +51,11 @@ pub enum EdgeSpawnAction {
 }
 
 impl EdgeSpawnDefinition {
-    pub fn enemy_b(position: Vec2) -> Self {
+    pub fn enemy_b(spawn_x: f32, ground_y: f32) -> Self {
+        let y = ground_y - SCREEN_HEIGHT * 0.5 + ENEMY_B_HEIGHT * 0.5;
         Self {
-            spawn_x: position.x,
-            spawn_position: Vec3::new(position.x, position.y, Z_ENEMY_BASE),
+            spawn_x,
+            spawn_position: Vec3::new(spawn_x, y, Z_ENEMY_BASE),
             width: ENEMY_B_WIDTH,
             edge: ScreenEdge::Right,
             action: EdgeSpawnAction::EnemyB,
@@ -135,28 +122,20 @@ impl EdgeSpawnAction {
     }
 }
 
-pub fn configure_default_spawns(mut manager: ResMut<EdgeSpawnManager>) {
-    if !manager.definitions.is_empty() {
-        return;
-    }
-
-    let coords = STATIC_SPAWN_COORDINATES;
-    let mut definitions = Vec::with_capacity(4);
-
-    definitions.push(EdgeSpawnDefinition::infected_enemy_robot(
-        coords.infected_enemy,
-        ScreenEdge::Right,
-    ));
-
-    let platform_y = 342.0 - SCREEN_HEIGHT * 0.5 + ENEMY_B_HEIGHT * 0.5;
-    for x in [
-        coords.grenade_thrower_1,
-        coords.grenade_thrower_2,
-        coords.grenade_thrower_3,
-    ] {
-        definitions.push(EdgeSpawnDefinition::enemy_b(Vec2::new(x, platform_y)));
+pub struct SpawnPlugin;
+
+impl Plugin for SpawnPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EdgeSpawnManager>()
+            .add_systems(FixedUpdate, (hangar_enemy_spawn_system, edge_spawn_system))
+            .add_systems(
+                FixedUpdate,
+                enemy_edge_cleanup_system
+                    .after(crate::enemy_c::enemy_c_movement_system)
+                    .after(crate::enemy_a::enemy_robot_behavior_system)
+                    .after(crate::enemy_b::enemy_b_behavior_system),
+            );
     }
-    manager.definitions = definitions;
 }
 
 pub fn hangar_enemy_spawn_system(
@@ -0,0 +1,52 @@
+//! Small settings blobs that outlive a session: `localStorage` in the browser,
+//! `<config dir>/gunday/<key>.ron` on native builds.
+
+#[derive(Debug, thiserror::Error)]
+pub enum StorageError {
+    #[error("no persistent storage is available")]
+    Unavailable,
+    #[error("could not write settings: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn load(key: &str) -> Option<String> {
+    let storage = web_sys::window()?.local_storage().ok()??;
+    storage.get_item(&format!("gunday.{key}")).ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn save(key: &str, value: &str) -> Result<(), StorageError> {
+    let storage = web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .ok_or(StorageError::Unavailable)?;
+    storage
+        .set_item(&format!("gunday.{key}"), value)
+        .map_err(|_| StorageError::Unavailable)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load(key: &str) -> Option<String> {
+    std::fs::read_to_string(settings_path(key)?).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save(key: &str, value: &str) -> Result<(), StorageError> {
+    let path = settings_path(key).ok_or(StorageError::Unavailable)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, value)?;
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn settings_path(key: &str) -> Option<std::path::PathBuf> {
+    use std::path::PathBuf;
+
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("gunday").join(format!("{key}.ron")))
+}
@@ -1,5 +1,5 @@
 use crate::components::*;
-use crate::constants::{SCREEN_HEIGHT, SCREEN_WIDTH, WORLD_WIDTH};
+use crate::constants::{SCREEN_HEIGHT, SCREEN_WIDTH};
 use bevy::prelude::*;
 
 #[derive(Resource)]
@@ -16,65 +16,16 @@ impl Default for PlayerControl {
 #[derive(Resource, Default, Clone, Copy)]
 pub struct WinMusic(pub bool);
 
-#[cfg(target_arch = "wasm32")]
-pub mod browser_events {
-    use js_sys::{Date, Object, Reflect};
-    use wasm_bindgen::prelude::JsValue;
-    use wasm_bindgen::prelude::*;
-    use web_sys::{CustomEvent, CustomEventInit, window};
-    fn encrypt(input: &str) -> String {
-        let bytes = input.as_bytes();
-        let mut result = String::with_capacity(bytes.len() + bytes.len() / 4);
-
-        for (index, &byte) in bytes.iter().enumerate() {
-            let adjusted = byte as u32 + 16 + index as u32;
-            let ch = char::from_u32(adjusted).unwrap_or('�');
-            result.push(ch);
-
-            if index != bytes.len() - 1 && (index + 1) % 4 == 0 {
-                result.push('-');
-            }
-        }
-
-        result
-    }
-
-    fn encrypted_timestamp() -> String {
-        let millis = Date::now() as u64;
-        let trimmed = millis / 10;
-        encrypt(&trimmed.to_string())
-    }
-    #[wasm_bindgen]
-    pub fn send_game_result(win: bool) {
-        if let Some(w) = window() {
-            let init = {
-                let tmp = CustomEventInit::new();
-
-                let _ = tmp.set_bubbles(true);
-                tmp
-            };
-
-            let obj = Object::new();
-            let _ = Reflect::set(&obj, &JsValue::from_str("win"), &JsValue::from_bool(win));
-            if win {
-                let code = encrypted_timestamp();
-                let _ = Reflect::set(&obj, &JsValue::from_str("code"), &JsValue::from_str(&code));
-            }
-            let detail: JsValue = obj.into();
-            let _ = init.set_detail(&detail);
-            if let Ok(event) = CustomEvent::new_with_event_init_dict("gunday-result", &init) {
-                let _ = w.dispatch_event(event.as_ref());
-            }
-        }
-    }
-}
-
-pub fn setup_camera(mut commands: Commands) {
+pub fn initial_camera_x(world_width: f32) -> f32 {
     let spawn_x = SCREEN_WIDTH / 4.0;
     let half_screen_width = SCREEN_WIDTH / 2.0;
     let world_left_bound = half_screen_width;
-    let world_right_bound = WORLD_WIDTH - half_screen_width;
-    let initial_camera_x = spawn_x.clamp(world_left_bound, world_right_bound);
+    let world_right_bound = world_width - half_screen_width;
+    spawn_x.clamp(world_left_bound, world_right_bound)
+}
+
+pub fn setup_camera(mut commands: Commands, world: Res<WorldBounds>) {
+    let initial_camera_x = initial_camera_x(world.width);
 
     commands.insert_resource(PlayerControl::default());
     commands.insert_resource(WinMusic::default());
@@ -92,10 +43,10 @@ pub fn setup_camera(mut commands: Commands) {
     ));
 }
 
-pub fn setup_layer_geometry(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    geometry_storage: Res<LayerGeometryStorage>,
+pub fn spawn_layer_geometry(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    geometry_storage: &LayerGeometryStorage,
 ) {
     for geometry in &geometry_storage.objects {
         let center_x = geometry.bottom_left.x + (geometry.width / 2.0);
@@ -112,6 +63,7 @@ pub fn setup_layer_geometry(
                 geometry.height,
             ),
             Solid,
+            LevelEntity,
         ));
     }
 }
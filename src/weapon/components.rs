@@ -0,0 +1,56 @@
+use super::config::*;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WeaponKind {
+    #[default]
+    Standard,
+    Spread,
+    Laser,
+    RapidFire,
+    Homing,
+    Flame,
+}
+
+impl WeaponKind {
+    /// Kinds that enemies can drop.
+    pub const DROPPABLE: [WeaponKind; 5] = [
+        WeaponKind::Spread,
+        WeaponKind::Laser,
+        WeaponKind::RapidFire,
+        WeaponKind::Homing,
+        WeaponKind::Flame,
+    ];
+
+    pub fn stats(self) -> &'static WeaponStats {
+        match self {
+            WeaponKind::Standard => &STANDARD_WEAPON,
+            WeaponKind::Spread => &SPREAD_WEAPON,
+            WeaponKind::Laser => &LASER_WEAPON,
+            WeaponKind::RapidFire => &RAPID_FIRE_WEAPON,
+            WeaponKind::Homing => &HOMING_WEAPON,
+            WeaponKind::Flame => &FLAME_WEAPON,
+        }
+    }
+}
+
+/// The gun the player is currently holding.
+#[derive(Component, Default)]
+pub struct Weapon {
+    pub kind: WeaponKind,
+}
+
+#[derive(Component)]
+pub struct WeaponPickup {
+    pub kind: WeaponKind,
+    pub base_y: f32,
+    pub age: f32,
+    /// Dropped pickups vanish after a while; placed ones stay.
+    pub lifetime: Option<f32>,
+}
+
+#[derive(Component)]
+pub struct HomingProjectile {
+    pub turn_rate: f32,
+}
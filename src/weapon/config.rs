@@ -0,0 +1,110 @@
+use crate::constants::{PROJECTILE_SIZE, PROJECTILE_SPEED};
+use crate::player::PLAYER_CONFIG;
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy)]
+pub struct WeaponStats {
+    pub projectile_count: u32,
+    /// Total fan angle the projectiles are spread over.
+    pub spread_degrees: f32,
+    pub speed: f32,
+    pub damage: u32,
+    pub cooldown: f32,
+    /// Drawn size of the projectile, which is also its hitbox.
+    pub size: Vec2,
+    /// Tint of the weapon's pickup. Projectiles use the weapon's own sprite.
+    pub color: Color,
+    /// Seconds before the projectile burns out on its own.
+    pub lifetime: Option<f32>,
+    /// Radians per second the projectile can turn towards an enemy.
+    pub homing_turn_rate: Option<f32>,
+}
+
+pub const STANDARD_WEAPON: WeaponStats = WeaponStats {
+    projectile_count: 1,
+    spread_degrees: 0.0,
+    speed: PROJECTILE_SPEED,
+    damage: 1,
+    cooldown: PLAYER_CONFIG.shot_cooldown,
+    size: Vec2::splat(PROJECTILE_SIZE),
+    color: Color::WHITE,
+    lifetime: None,
+    homing_turn_rate: None,
+};
+
+pub const SPREAD_WEAPON: WeaponStats = WeaponStats {
+    projectile_count: 5,
+    spread_degrees: 40.0,
+    speed: 1200.0,
+    damage: 1,
+    cooldown: 0.3,
+    size: Vec2::splat(10.0),
+    color: Color::srgb(1.0, 0.6, 0.3),
+    lifetime: None,
+    homing_turn_rate: None,
+};
+
+pub const LASER_WEAPON: WeaponStats = WeaponStats {
+    projectile_count: 1,
+    spread_degrees: 0.0,
+    speed: 2400.0,
+    damage: 3,
+    cooldown: 0.35,
+    size: Vec2::new(36.0, 4.0),
+    color: Color::srgb(0.4, 0.9, 1.0),
+    lifetime: None,
+    homing_turn_rate: None,
+};
+
+pub const RAPID_FIRE_WEAPON: WeaponStats = WeaponStats {
+    projectile_count: 1,
+    spread_degrees: 0.0,
+    speed: PROJECTILE_SPEED,
+    damage: 1,
+    cooldown: 0.06,
+    size: Vec2::splat(10.0),
+    color: Color::srgb(1.0, 0.95, 0.4),
+    lifetime: None,
+    homing_turn_rate: None,
+};
+
+pub const HOMING_WEAPON: WeaponStats = WeaponStats {
+    projectile_count: 2,
+    spread_degrees: 30.0,
+    speed: 700.0,
+    damage: 2,
+    cooldown: 0.3,
+    size: Vec2::splat(PROJECTILE_SIZE),
+    color: Color::srgb(0.5, 1.0, 0.5),
+    lifetime: None,
+    homing_turn_rate: Some(6.0),
+};
+
+pub const FLAME_WEAPON: WeaponStats = WeaponStats {
+    projectile_count: 3,
+    spread_degrees: 24.0,
+    speed: 500.0,
+    damage: 1,
+    cooldown: 0.08,
+    size: Vec2::splat(20.0),
+    color: Color::srgb(1.0, 0.4, 0.1),
+    lifetime: Some(0.35),
+    homing_turn_rate: None,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct WeaponPickupConfig {
+    pub size: Vec2,
+    pub drop_chance: f32,
+    pub drop_lifetime: f32,
+    pub bob_amplitude: f32,
+    pub bob_speed: f32,
+}
+
+pub const WEAPON_PICKUP_CONFIG: WeaponPickupConfig = WeaponPickupConfig {
+    size: Vec2::splat(24.0),
+    drop_chance: 0.15,
+    drop_lifetime: 8.0,
+    bob_amplitude: 4.0,
+    bob_speed: 4.0,
+};
@@ -0,0 +1,46 @@
+use super::components::HomingProjectile;
+use crate::enemy_a::robot_components::{EnemyDespawnTimer, EnemyRobot};
+use crate::enemy_b::components::{EnemyB, EnemyBDespawnTimer};
+use crate::enemy_c::components::{EnemyC, EnemyCDespawnTimer};
+use crate::miniboss::components::Miniboss;
+use crate::projectile::components::Projectile;
+use bevy::prelude::*;
+
+type HomingTarget = (
+    Or<(With<EnemyRobot>, With<EnemyB>, With<EnemyC>, With<Miniboss>)>,
+    Without<EnemyDespawnTimer>,
+    Without<EnemyBDespawnTimer>,
+    Without<EnemyCDespawnTimer>,
+    Without<Projectile>,
+);
+
+/// Steers homing projectiles towards the nearest living enemy.
+pub fn homing_projectile_system(
+    time: Res<Time>,
+    mut projectiles: Query<(&mut Projectile, &mut Transform, &HomingProjectile)>,
+    targets: Query<&Transform, HomingTarget>,
+) {
+    let dt = time.delta_secs();
+    for (mut projectile, mut transform, homing) in projectiles.iter_mut() {
+        let position = transform.translation.truncate();
+        let nearest = targets
+            .iter()
+            .map(|target| target.translation.truncate() - position)
+            .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()));
+        let Some(to_target) = nearest else {
+            continue;
+        };
+
+        let current = projectile.direction.to_angle();
+        let wanted = to_target.to_angle();
+        let mut turn = wanted - current;
+        // Take the short way round.
+        turn =
+            (turn + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI;
+        let max_turn = homing.turn_rate * dt;
+        let angle = current + turn.clamp(-max_turn, max_turn);
+
+        projectile.direction = Vec2::from_angle(angle);
+        transform.rotation = Quat::from_rotation_z(angle);
+    }
+}
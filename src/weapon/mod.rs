@@ -0,0 +1,13 @@
+pub mod components;
+pub mod config;
+pub mod homing_system;
+pub mod pickup_system;
+pub mod plugin;
+
+pub use components::{HomingProjectile, Weapon, WeaponKind, WeaponPickup};
+pub use config::WeaponStats;
+pub use homing_system::homing_projectile_system;
+pub use pickup_system::{
+    spawn_weapon_pickup, weapon_drop_system, weapon_pickup_bob_system, weapon_pickup_collect_system,
+};
+pub use plugin::WeaponPlugin;
@@ -0,0 +1,112 @@
+use super::components::{Weapon, WeaponKind, WeaponPickup};
+use super::config::WEAPON_PICKUP_CONFIG;
+use crate::assets::GameAssets;
+use crate::collision::rectangles_collide;
+use crate::components::{CollectedItem, EnemyDefeatedEvent, PickupCollectedEvent};
+use crate::constants::Z_PROJECTILES;
+use crate::player::components::{Player, PlayerRespawning, ShootingState};
+use crate::player::setup_player::SpriteSize;
+use crate::rng::GameRng;
+use bevy::prelude::*;
+
+type CollectingPlayer = (With<Player>, Without<PlayerRespawning>);
+
+pub fn spawn_weapon_pickup(
+    commands: &mut Commands,
+    assets: &GameAssets,
+    kind: WeaponKind,
+    position: Vec2,
+    lifetime: Option<f32>,
+) -> Entity {
+    commands
+        .spawn((
+            Sprite {
+                image: assets.player_projectile.clone(),
+                color: kind.stats().color,
+                custom_size: Some(WEAPON_PICKUP_CONFIG.size),
+                ..default()
+            },
+            Transform::from_translation(position.extend(Z_PROJECTILES)),
+            WeaponPickup {
+                kind,
+                base_y: position.y,
+                age: 0.0,
+                lifetime,
+            },
+        ))
+        .id()
+}
+
+pub fn weapon_drop_system(
+    mut commands: Commands,
+    mut defeated: EventReader<EnemyDefeatedEvent>,
+    mut rng: ResMut<GameRng>,
+    assets: Res<GameAssets>,
+) {
+    for event in defeated.read() {
+        if rng.f32() >= WEAPON_PICKUP_CONFIG.drop_chance {
+            continue;
+        }
+        let kind = WeaponKind::DROPPABLE[rng.usize(0..WeaponKind::DROPPABLE.len())];
+        spawn_weapon_pickup(
+            &mut commands,
+            &assets,
+            kind,
+            event.position.truncate(),
+            Some(WEAPON_PICKUP_CONFIG.drop_lifetime),
+        );
+    }
+}
+
+pub fn weapon_pickup_bob_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut WeaponPickup, &mut Transform)>,
+) {
+    for (entity, mut pickup, mut transform) in query.iter_mut() {
+        pickup.age += time.delta_secs();
+        if pickup
+            .lifetime
+            .is_some_and(|lifetime| pickup.age >= lifetime)
+        {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation.y = pickup.base_y
+            + (pickup.age * WEAPON_PICKUP_CONFIG.bob_speed).sin()
+                * WEAPON_PICKUP_CONFIG.bob_amplitude;
+    }
+}
+
+pub fn weapon_pickup_collect_system(
+    mut commands: Commands,
+    mut player_query: Query<
+        (&Transform, &SpriteSize, &mut Weapon, &mut ShootingState),
+        CollectingPlayer,
+    >,
+    pickups: Query<(Entity, &Transform, &WeaponPickup), Without<Player>>,
+    mut collected: EventWriter<PickupCollectedEvent>,
+) {
+    for (player_transform, sprite_size, mut weapon, mut shooting_state) in player_query.iter_mut() {
+        let player_size = Vec2::new(sprite_size.width, sprite_size.height);
+        let player_min = player_transform.translation.truncate() - player_size * 0.5;
+
+        for (entity, transform, pickup) in pickups.iter() {
+            let pickup_min = transform.translation.truncate() - WEAPON_PICKUP_CONFIG.size * 0.5;
+            if !rectangles_collide(
+                player_min,
+                player_size,
+                pickup_min,
+                WEAPON_PICKUP_CONFIG.size,
+            ) {
+                continue;
+            }
+            weapon.kind = pickup.kind;
+            shooting_state.shot_cooldown = pickup.kind.stats().cooldown;
+            collected.write(PickupCollectedEvent {
+                item: CollectedItem::Weapon(pickup.kind),
+            });
+            commands.entity(entity).despawn();
+        }
+    }
+}
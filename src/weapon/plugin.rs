@@ -0,0 +1,20 @@
+use super::*;
+use crate::player::player_movement_system;
+use crate::projectile::projectile_movement_system;
+use bevy::prelude::*;
+
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (
+                homing_projectile_system.before(projectile_movement_system),
+                weapon_drop_system,
+                weapon_pickup_bob_system,
+                weapon_pickup_collect_system.after(player_movement_system),
+            ),
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioSource;
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use gunday::GamePlugin;
+use gunday::components::{LevelEntity, QuitRequested};
+use gunday::constants::SIMULATION_HZ;
+use gunday::level::{Campaign, CurrentLevel, LevelCompleted};
+use gunday::replay::{InputPlayback, Replay};
+use gunday::rng::GameRng;
+use std::time::Duration;
+
+const SEED: u64 = 42;
+/// Frames to wait for the loading screen. Assets load on background threads,
+/// so the manual clock alone doesn't bound it.
+const MAX_LOADING_FRAMES: usize = 2000;
+
+/// The game without a window or renderer. An empty replay stands in for the
+/// player, which also skips the title screen.
+fn headless_app(campaign: Campaign) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            file_path: "page/assets".into(),
+            ..default()
+        },
+        InputPlugin,
+        StatesPlugin,
+    ))
+    .init_asset::<Image>()
+    .init_asset::<Mesh>()
+    .init_asset::<AudioSource>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+        1.0 / SIMULATION_HZ,
+    )))
+    .insert_resource(InputPlayback::new(Replay {
+        seed: SEED,
+        frames: Vec::new(),
+    }))
+    .insert_resource(campaign)
+    .add_plugins(GamePlugin);
+    app
+}
+
+/// Updates until `done` holds, giving background asset loads time to finish.
+fn update_until(app: &mut App, what: &str, done: impl Fn(&World) -> bool) {
+    for _ in 0..MAX_LOADING_FRAMES {
+        app.update();
+        if done(app.world()) {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    panic!("{what} never happened");
+}
+
+fn start_run(app: &mut App) {
+    update_until(app, "run start", |world| {
+        !world.resource::<Time<Virtual>>().is_paused()
+    });
+}
+
+fn level_entities(app: &mut App) -> Vec<Entity> {
+    app.world_mut()
+        .query_filtered::<Entity, With<LevelEntity>>()
+        .iter(app.world())
+        .collect()
+}
+
+#[test]
+fn game_plugin_ticks_fixed_update_headless() {
+    let mut app = headless_app(Campaign::default());
+    start_run(&mut app);
+
+    let fixed = app.world().resource::<Time<Fixed>>();
+    let (before, timestep) = (fixed.elapsed(), fixed.timestep());
+    for _ in 0..10 {
+        app.update();
+    }
+
+    let after = app.world().resource::<Time<Fixed>>().elapsed();
+    assert_eq!(after - before, timestep * 10);
+    assert_eq!(app.world().resource::<GameRng>().seed(), SEED);
+}
+
+#[test]
+fn completed_level_moves_on_to_the_next_one() {
+    static LEVELS: &[&str] = &["levels/level.level.ron", "levels/level.level.ron"];
+    let mut app = headless_app(Campaign::new(LEVELS));
+    start_run(&mut app);
+    assert!(!app.world().resource::<Campaign>().is_final_level());
+
+    let first_level = level_entities(&mut app);
+    assert!(!first_level.is_empty());
+
+    app.world_mut().send_event(LevelCompleted);
+    update_until(&mut app, "second level start", |world| {
+        world.resource::<CurrentLevel>().applied && world.resource::<Campaign>().is_final_level()
+    });
+    app.update();
+
+    let second_level = level_entities(&mut app);
+    assert!(!second_level.is_empty());
+    assert!(
+        first_level
+            .iter()
+            .all(|entity| !second_level.contains(entity))
+    );
+    assert!(
+        first_level
+            .iter()
+            .all(|&entity| app.world().get_entity(entity).is_err())
+    );
+}
+
+#[test]
+fn quit_exits_the_native_app() {
+    let mut app = headless_app(Campaign::default());
+    start_run(&mut app);
+
+    app.world_mut().send_event(QuitRequested);
+    app.update();
+
+    assert_eq!(app.should_exit(), Some(AppExit::Success));
+}